@@ -0,0 +1,382 @@
+//! Decoding and encoding of the supported encodings.
+
+use std::{fmt, str::FromStr};
+
+use gsm0338::{Gsm7Char, Gsm7ErrorKind, Gsm7Str, Gsm7String};
+use iso8859_1::{cp1252, IsoLatin1Char, IsoLatin1Str, IsoLatin1String, Transliteration};
+
+/// The encodings supported by the tool.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Encoding {
+    Utf8,
+    Latin1,
+    Cp1252,
+    /// GSM 03.38, as unpacked septets.
+    Gsm0338,
+}
+
+impl FromStr for Encoding {
+    type Err = String;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name.to_ascii_lowercase().as_str() {
+            "utf-8" | "utf8" => Ok(Encoding::Utf8),
+            "iso-8859-1" | "iso8859-1" | "latin1" | "l1" => Ok(Encoding::Latin1),
+            "windows-1252" | "cp1252" => Ok(Encoding::Cp1252),
+            "gsm0338" | "gsm-03.38" | "gsm" => Ok(Encoding::Gsm0338),
+            _ => Err(format!("unknown encoding `{name}`")),
+        }
+    }
+}
+
+impl fmt::Display for Encoding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Encoding::Utf8 => "UTF-8",
+            Encoding::Latin1 => "ISO-8859-1",
+            Encoding::Cp1252 => "Windows-1252",
+            Encoding::Gsm0338 => "GSM 03.38",
+        })
+    }
+}
+
+/// What to do with the input that can not be converted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    /// Fail on the first error.
+    Strict,
+    /// Replace invalid input with `U+FFFD` when decoding, and unencodable characters with `?`
+    /// when encoding.
+    Lossy,
+    /// Like `Lossy`, but transliterate unencodable characters when possible.
+    Transliterate,
+}
+
+impl FromStr for Mode {
+    type Err = String;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "strict" => Ok(Mode::Strict),
+            "lossy" => Ok(Mode::Lossy),
+            "translit" | "transliterate" => Ok(Mode::Transliterate),
+            _ => Err(format!("unknown mode `{name}`")),
+        }
+    }
+}
+
+/// The error returned when the input is not valid in its encoding.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecodeError {
+    /// The index of the first invalid byte.
+    pub position: usize,
+    pub message: String,
+}
+
+/// The error returned when a character can not be encoded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EncodeError {
+    /// The byte index of the character in the UTF-8 text.
+    pub position: usize,
+    pub char: char,
+}
+
+/// Decodes `bytes` from `encoding`.
+pub fn decode(bytes: &[u8], encoding: Encoding, mode: Mode) -> Result<String, DecodeError> {
+    let strict = mode == Mode::Strict;
+    match encoding {
+        Encoding::Utf8 if strict => match std::str::from_utf8(bytes) {
+            Ok(text) => Ok(text.to_owned()),
+            Err(err) => Err(DecodeError {
+                position: err.valid_up_to(),
+                message: "invalid UTF-8 sequence".to_owned(),
+            }),
+        },
+        Encoding::Utf8 => Ok(String::from_utf8_lossy(bytes).into_owned()),
+        Encoding::Latin1 if strict => match IsoLatin1Str::from_iso8859_1(bytes) {
+            Ok(s) => Ok(s.to_string()),
+            Err(err) => Err(DecodeError {
+                position: err.valid_up_to(),
+                message: format!("byte 0x{:02X} undefined in ISO-8859-1", err.invalid_byte()),
+            }),
+        },
+        Encoding::Latin1 => Ok(bytes
+            .iter()
+            .map(|&byte| {
+                IsoLatin1Char::try_from(byte)
+                    .map(char::from)
+                    .unwrap_or(char::REPLACEMENT_CHARACTER)
+            })
+            .collect()),
+        Encoding::Cp1252 if strict => {
+            match bytes.iter().position(|&byte| is_cp1252_undefined(byte)) {
+                Some(position) => Err(DecodeError {
+                    position,
+                    message: format!("byte 0x{:02X} undefined in Windows-1252", bytes[position]),
+                }),
+                None => Ok(cp1252::decode(bytes)),
+            }
+        }
+        Encoding::Cp1252 => Ok(bytes
+            .iter()
+            .map(|&byte| {
+                if is_cp1252_undefined(byte) {
+                    char::REPLACEMENT_CHARACTER
+                } else {
+                    cp1252::decode_byte(byte)
+                }
+            })
+            .collect()),
+        Encoding::Gsm0338 => decode_gsm0338(bytes, strict),
+    }
+}
+
+/// Returns `true` if Windows-1252 leaves `byte` undefined.
+///
+/// [`cp1252::decode`] maps those bytes to the C1 control codes of the same value, which are
+/// treated as invalid here instead.
+fn is_cp1252_undefined(byte: u8) -> bool {
+    matches!(byte, 0x81 | 0x8D | 0x8F | 0x90 | 0x9D)
+}
+
+/// Decodes unpacked GSM 03.38 septets, replacing every invalid septet with `U+FFFD` unless
+/// `strict`.
+fn decode_gsm0338(mut septets: &[u8], strict: bool) -> Result<String, DecodeError> {
+    let mut text = String::with_capacity(septets.len());
+    let mut offset = 0;
+    loop {
+        match Gsm7Str::from_septets(septets) {
+            Ok(s) => {
+                text.extend(s.chars().map(char::from));
+                return Ok(text);
+            }
+            Err(err) if strict => {
+                return Err(DecodeError {
+                    position: offset + err.valid_up_to(),
+                    message: match err.kind() {
+                        Gsm7ErrorKind::NotASeptet => "byte is not a septet",
+                        Gsm7ErrorKind::UnknownExtension => "escape to unknown extension character",
+                        _ => "incomplete escape",
+                    }
+                    .to_owned(),
+                })
+            }
+            Err(err) => {
+                let valid = &septets[..err.valid_up_to()];
+                // SAFETY: validated up to `valid_up_to`.
+                let valid = unsafe { Gsm7Str::from_septets_unchecked(valid) };
+                text.extend(valid.chars().map(char::from));
+                text.push(char::REPLACEMENT_CHARACTER);
+
+                offset += err.valid_up_to() + 1;
+                septets = &septets[err.valid_up_to() + 1..];
+            }
+        }
+    }
+}
+
+/// Encodes `text` into `encoding`.
+pub fn encode(text: &str, encoding: Encoding, mode: Mode) -> Result<Vec<u8>, EncodeError> {
+    match encoding {
+        Encoding::Utf8 => Ok(text.as_bytes().to_vec()),
+        Encoding::Latin1 => encode_latin1(text, mode),
+        Encoding::Cp1252 => encode_cp1252(text, mode),
+        Encoding::Gsm0338 => encode_gsm0338(text, mode),
+    }
+}
+
+fn encode_latin1(text: &str, mode: Mode) -> Result<Vec<u8>, EncodeError> {
+    let s = match mode {
+        Mode::Strict => text.parse::<IsoLatin1String>().map_err(|err| EncodeError {
+            position: err.valid_up_to(),
+            char: err.char(),
+        })?,
+        Mode::Lossy => IsoLatin1String::from_str_with(text, &Transliteration::empty()),
+        Mode::Transliterate => IsoLatin1String::from_str_lossy(text),
+    };
+    Ok(s.into_bytes())
+}
+
+fn encode_cp1252(text: &str, mode: Mode) -> Result<Vec<u8>, EncodeError> {
+    let rules = Transliteration::new();
+
+    let mut bytes = Vec::with_capacity(text.len());
+    for (position, char) in text.char_indices() {
+        if let Some(byte) = cp1252_byte(char) {
+            bytes.push(byte);
+            continue;
+        }
+
+        match mode {
+            Mode::Strict => return Err(EncodeError { position, char }),
+            Mode::Lossy => bytes.push(b'?'),
+            // ISO8859-1 is a subset of Windows-1252.
+            Mode::Transliterate => bytes.extend_from_slice(rules.transliterate(char).as_bytes()),
+        }
+    }
+    Ok(bytes)
+}
+
+/// Encodes a character into Windows-1252, the inverse of [`cp1252::decode_byte`] for the defined
+/// bytes.
+fn cp1252_byte(char: char) -> Option<u8> {
+    match IsoLatin1Char::try_from(char) {
+        Ok(char) => Some(u8::from(char)),
+        Err(_) => (0x80..=0x9F)
+            .filter(|&byte| !is_cp1252_undefined(byte))
+            .find(|&byte| cp1252::decode_byte(byte) == char),
+    }
+}
+
+fn encode_gsm0338(text: &str, mode: Mode) -> Result<Vec<u8>, EncodeError> {
+    let rules = Transliteration::new();
+    let question_mark = Gsm7Char::try_from('?').expect("`?` is in GSM 03.38");
+
+    let mut s = Gsm7String::new();
+    for (position, char) in text.char_indices() {
+        if let Ok(char) = Gsm7Char::try_from(char) {
+            s.push(char);
+            continue;
+        }
+
+        match mode {
+            Mode::Strict => return Err(EncodeError { position, char }),
+            Mode::Lossy => s.push(question_mark),
+            // Transliterate to ISO8859-1 first, whose characters are mostly in GSM 03.38.
+            Mode::Transliterate => s.extend(
+                rules
+                    .transliterate(char)
+                    .chars()
+                    .map(|char| Gsm7Char::try_from(char::from(char)).unwrap_or(question_mark)),
+            ),
+        }
+    }
+    Ok(s.into_septets())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_names() {
+        assert_eq!("UTF-8".parse(), Ok(Encoding::Utf8));
+        assert_eq!("latin1".parse(), Ok(Encoding::Latin1));
+        assert_eq!("Windows-1252".parse(), Ok(Encoding::Cp1252));
+        assert_eq!("gsm".parse(), Ok(Encoding::Gsm0338));
+        assert!("ebcdic".parse::<Encoding>().is_err());
+        assert_eq!("translit".parse(), Ok(Mode::Transliterate));
+    }
+
+    #[test]
+    fn decode_strict() {
+        let err = decode(b"caf\xE9", Encoding::Utf8, Mode::Strict).unwrap_err();
+        assert_eq!(err.position, 3);
+
+        assert_eq!(
+            decode(b"caf\xE9", Encoding::Latin1, Mode::Strict).unwrap(),
+            "café"
+        );
+        let err = decode(b"ab\x85", Encoding::Latin1, Mode::Strict).unwrap_err();
+        assert_eq!(err.position, 2);
+        assert_eq!(err.message, "byte 0x85 undefined in ISO-8859-1");
+
+        assert_eq!(
+            decode(b"\x93ok\x94", Encoding::Cp1252, Mode::Strict).unwrap(),
+            "“ok”"
+        );
+        for byte in [0x81, 0x8D, 0x8F, 0x90, 0x9D] {
+            let err = decode(&[b'a', byte], Encoding::Cp1252, Mode::Strict).unwrap_err();
+            assert_eq!(err.position, 1);
+            assert_eq!(
+                err.message,
+                format!("byte 0x{byte:02X} undefined in Windows-1252")
+            );
+        }
+
+        assert_eq!(
+            decode(b"\x1B\x65 5", Encoding::Gsm0338, Mode::Strict).unwrap(),
+            "€ 5"
+        );
+        let err = decode(b"ok\x1B", Encoding::Gsm0338, Mode::Strict).unwrap_err();
+        assert_eq!(err.position, 2);
+        assert_eq!(err.message, "incomplete escape");
+    }
+
+    #[test]
+    fn decode_lossy() {
+        let lossy = |bytes: &[u8], encoding| decode(bytes, encoding, Mode::Lossy).unwrap();
+        assert_eq!(lossy(b"caf\xE9", Encoding::Utf8), "caf\u{FFFD}");
+        assert_eq!(lossy(b"a\x85b", Encoding::Latin1), "a\u{FFFD}b");
+        assert_eq!(lossy(b"\x80\x81", Encoding::Cp1252), "€\u{FFFD}");
+        assert_eq!(
+            lossy(b"a\x80\x1B\x41b", Encoding::Gsm0338),
+            "a\u{FFFD}\u{FFFD}Ab"
+        );
+    }
+
+    #[test]
+    fn encode_modes() {
+        let text = "Œuvre à 5€ ✓";
+
+        let err = encode(text, Encoding::Latin1, Mode::Strict).unwrap_err();
+        assert_eq!(
+            err,
+            EncodeError {
+                position: 0,
+                char: 'Œ'
+            }
+        );
+        assert_eq!(
+            encode(text, Encoding::Latin1, Mode::Lossy).unwrap(),
+            b"?uvre \xE0 5? ?"
+        );
+        assert_eq!(
+            encode(text, Encoding::Latin1, Mode::Transliterate).unwrap(),
+            b"OEuvre \xE0 5EUR ?"
+        );
+
+        let err = encode(text, Encoding::Gsm0338, Mode::Strict).unwrap_err();
+        assert_eq!(err.char, 'Œ');
+        assert_eq!(
+            encode(text, Encoding::Gsm0338, Mode::Lossy).unwrap(),
+            b"?uvre \x7F 5\x1B\x65 ?"
+        );
+        assert_eq!(
+            encode("Œ â", Encoding::Gsm0338, Mode::Transliterate).unwrap(),
+            b"OE ?"
+        );
+
+        assert_eq!(
+            encode(text, Encoding::Cp1252, Mode::Strict).unwrap_err(),
+            EncodeError {
+                position: 15,
+                char: '✓'
+            }
+        );
+        assert_eq!(
+            encode(text, Encoding::Cp1252, Mode::Lossy).unwrap(),
+            b"\x8Cuvre \xE0 5\x80 ?"
+        );
+        assert_eq!(
+            encode("“a→b” ✓", Encoding::Cp1252, Mode::Transliterate).unwrap(),
+            b"\x93a->b\x94 ?"
+        );
+
+        assert_eq!(
+            encode(text, Encoding::Utf8, Mode::Strict).unwrap(),
+            text.as_bytes()
+        );
+    }
+
+    #[test]
+    fn cp1252_round_trip() {
+        for byte in (0x00..=0xFF).filter(|&byte| !is_cp1252_undefined(byte)) {
+            let char = cp1252::decode_byte(byte);
+            assert_eq!(cp1252_byte(char), Some(byte), "0x{byte:02X}");
+        }
+        assert_eq!(cp1252_byte('\u{80}'), None);
+        assert_eq!(cp1252_byte('\u{81}'), None);
+        assert_eq!(cp1252_byte('Ŋ'), None);
+    }
+}
@@ -0,0 +1,134 @@
+//! Guessing of the encoding of some bytes.
+
+use iso8859_1::{mixed, mojibake, IsoLatin1Char, IsoLatin1Str};
+
+use crate::codec::Encoding;
+
+/// A likely encoding of the input, with the reason it was chosen.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Guess {
+    pub encoding: Encoding,
+    pub reason: String,
+}
+
+impl Guess {
+    fn new(encoding: Encoding, reason: impl Into<String>) -> Self {
+        Guess {
+            encoding,
+            reason: reason.into(),
+        }
+    }
+}
+
+/// Returns the likely encodings of `bytes`, the most likely first.
+pub fn detect(bytes: &[u8]) -> Vec<Guess> {
+    let mut guesses = Vec::new();
+
+    if bytes.is_ascii() {
+        guesses.push(Guess::new(Encoding::Utf8, "ASCII only"));
+        guesses.push(Guess::new(Encoding::Latin1, "ASCII only"));
+        if bytes.iter().any(|&byte| is_gsm_letter(byte)) {
+            guesses.push(Guess::new(
+                Encoding::Gsm0338,
+                "control codes used as GSM 03.38 letters",
+            ));
+        }
+        return guesses;
+    }
+
+    if let Ok(text) = std::str::from_utf8(bytes) {
+        let reason = if is_double_encoded(text) {
+            "valid UTF-8, but likely encoded twice (UTF-8 text decoded as ISO-8859-1)"
+        } else {
+            "valid UTF-8"
+        };
+        guesses.push(Guess::new(Encoding::Utf8, reason));
+        return guesses;
+    }
+
+    let (_, fallbacks) = mixed::decode(bytes, mixed::Fallback::Cp1252);
+    let fallback_len: usize = fallbacks.iter().map(|range| range.len()).sum();
+    let non_ascii = bytes.iter().filter(|byte| !byte.is_ascii()).count();
+    if fallback_len < non_ascii {
+        guesses.push(Guess::new(
+            Encoding::Utf8,
+            format!(
+                "mostly UTF-8, with {} region(s) in a legacy encoding",
+                fallbacks.len()
+            ),
+        ));
+    }
+
+    match IsoLatin1Str::from_iso8859_1(bytes) {
+        Ok(_) => {
+            guesses.push(Guess::new(Encoding::Latin1, "valid ISO-8859-1"));
+            guesses.push(Guess::new(Encoding::Cp1252, "superset of ISO-8859-1"));
+        }
+        Err(_) => guesses.push(Guess::new(
+            Encoding::Cp1252,
+            "bytes in 0x80..=0x9F, undefined in ISO-8859-1",
+        )),
+    }
+    guesses
+}
+
+/// Returns `true` if `byte` is a control code in ASCII but a letter in GSM 03.38.
+fn is_gsm_letter(byte: u8) -> bool {
+    byte < 0x20 && !matches!(byte, b'\t' | b'\n' | b'\r' | 0x1B)
+}
+
+/// Returns `true` if `text` looks like UTF-8 that was decoded as ISO8859-1 and encoded again.
+fn is_double_encoded(text: &str) -> bool {
+    let bytes: Option<Vec<u8>> = text
+        .chars()
+        .map(|char| IsoLatin1Char::try_from(char).ok().map(u8::from))
+        .collect();
+    match bytes {
+        Some(bytes) => IsoLatin1Str::from_iso8859_1(&bytes).map_or(false, mojibake::is_mojibake),
+        None => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn encodings(bytes: &[u8]) -> Vec<Encoding> {
+        detect(bytes)
+            .into_iter()
+            .map(|guess| guess.encoding)
+            .collect()
+    }
+
+    #[test]
+    fn ascii() {
+        assert_eq!(encodings(b"plain\n"), [Encoding::Utf8, Encoding::Latin1]);
+        assert_eq!(
+            encodings(b"\x10 \x15"),
+            [Encoding::Utf8, Encoding::Latin1, Encoding::Gsm0338]
+        );
+    }
+
+    #[test]
+    fn utf8() {
+        let guesses = detect("Grüße".as_bytes());
+        assert_eq!(guesses, [Guess::new(Encoding::Utf8, "valid UTF-8")]);
+
+        let guesses = detect("Ã©tÃ©".as_bytes());
+        assert_eq!(guesses.len(), 1);
+        assert!(guesses[0].reason.contains("encoded twice"));
+    }
+
+    #[test]
+    fn legacy() {
+        assert_eq!(
+            encodings(b"Gr\xFC\xDFe"),
+            [Encoding::Latin1, Encoding::Cp1252]
+        );
+        assert_eq!(encodings(b"\x93ok\x94"), [Encoding::Cp1252]);
+        assert_eq!(
+            encodings(b"caf\xC3\xA9 / caf\xE9"),
+            [Encoding::Utf8, Encoding::Latin1, Encoding::Cp1252]
+        );
+    }
+}
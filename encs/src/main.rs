@@ -0,0 +1,303 @@
+//! # encs
+//!
+//! Validates, transcodes and detects the encoding of files, using the crates of this workspace.
+//!
+//! ```text
+//! encs validate <ENCODING> [FILE]
+//! encs transcode <FROM> <TO> [--mode strict|lossy|translit] [FILE]
+//! encs detect [FILE]
+//! ```
+//!
+//! The input is read from standard input when `FILE` is missing or `-`, and transcoded text is
+//! written to standard output. The supported encodings are `utf-8`, `iso-8859-1`, `windows-1252`
+//! and `gsm0338` (as unpacked septets).
+//!
+//! Errors are reported with their line and their column, counted in characters.
+
+use std::{
+    env, fs,
+    io::{self, Read, Write},
+    process::ExitCode,
+};
+
+mod codec;
+mod detect;
+
+use codec::{Encoding, Mode};
+
+const USAGE: &str = "\
+usage: encs validate <ENCODING> [FILE]
+       encs transcode <FROM> <TO> [--mode strict|lossy|translit] [FILE]
+       encs detect [FILE]
+
+encodings: utf-8, iso-8859-1, windows-1252, gsm0338 (unpacked septets)";
+
+/// A command given on the command line.
+#[derive(Debug, PartialEq, Eq)]
+enum Command {
+    Validate {
+        encoding: Encoding,
+        file: Option<String>,
+    },
+    Transcode {
+        from: Encoding,
+        to: Encoding,
+        mode: Mode,
+        file: Option<String>,
+    },
+    Detect {
+        file: Option<String>,
+    },
+}
+
+impl Command {
+    /// Parses the arguments, without the program name.
+    fn parse(args: &[String]) -> Result<Command, String> {
+        let mut mode = Mode::Strict;
+        let mut positional = Vec::new();
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--mode" => {
+                    let value = args.next().ok_or("missing value for `--mode`")?;
+                    mode = value.parse()?;
+                }
+                "-" => positional.push(arg.as_str()),
+                _ if arg.starts_with('-') => return Err(format!("unknown option `{arg}`")),
+                _ => positional.push(arg.as_str()),
+            }
+        }
+
+        let file = |index: usize| match positional.get(index) {
+            Some(&"-") | None => None,
+            Some(file) => Some(file.to_string()),
+        };
+        let max_args = |max: usize| {
+            if positional.len() > max {
+                Err(format!("unexpected argument `{}`", positional[max]))
+            } else {
+                Ok(())
+            }
+        };
+        let encoding = |index: usize| -> Result<Encoding, String> {
+            positional
+                .get(index)
+                .ok_or_else(|| "missing encoding".to_owned())?
+                .parse()
+        };
+
+        let command = match positional.first() {
+            Some(&"validate") => {
+                max_args(3)?;
+                Command::Validate {
+                    encoding: encoding(1)?,
+                    file: file(2),
+                }
+            }
+            Some(&"transcode") => {
+                max_args(4)?;
+                Command::Transcode {
+                    from: encoding(1)?,
+                    to: encoding(2)?,
+                    mode,
+                    file: file(3),
+                }
+            }
+            Some(&"detect") => {
+                max_args(2)?;
+                Command::Detect { file: file(1) }
+            }
+            Some(command) => return Err(format!("unknown command `{command}`")),
+            None => return Err("missing command".to_owned()),
+        };
+        Ok(command)
+    }
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+    if args.iter().any(|arg| arg == "--help" || arg == "-h") {
+        println!("{USAGE}");
+        return ExitCode::SUCCESS;
+    }
+
+    let command = match Command::parse(&args) {
+        Ok(command) => command,
+        Err(err) => {
+            eprintln!("error: {err}\n\n{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+
+    match run(command) {
+        Ok(code) => code,
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::from(2)
+        }
+    }
+}
+
+/// Runs `command`, returning a failure code if the input is invalid.
+fn run(command: Command) -> io::Result<ExitCode> {
+    match command {
+        Command::Validate { encoding, file } => {
+            let bytes = read(file.as_deref())?;
+            match codec::decode(&bytes, encoding, Mode::Strict) {
+                Ok(_) => {
+                    println!("valid {encoding}");
+                    Ok(ExitCode::SUCCESS)
+                }
+                Err(err) => {
+                    let (line, column) = decoded_line_column(&bytes[..err.position], encoding);
+                    eprintln!(
+                        "invalid {encoding}: {} at byte {} (line {line}, character {column})",
+                        err.message, err.position
+                    );
+                    Ok(ExitCode::FAILURE)
+                }
+            }
+        }
+        Command::Transcode {
+            from,
+            to,
+            mode,
+            file,
+        } => {
+            let bytes = read(file.as_deref())?;
+            let text = match codec::decode(&bytes, from, mode) {
+                Ok(text) => text,
+                Err(err) => {
+                    let (line, column) = decoded_line_column(&bytes[..err.position], from);
+                    eprintln!(
+                        "invalid {from}: {} at byte {} (line {line}, character {column})",
+                        err.message, err.position
+                    );
+                    return Ok(ExitCode::FAILURE);
+                }
+            };
+            match codec::encode(&text, to, mode) {
+                Ok(encoded) => {
+                    io::stdout().write_all(&encoded)?;
+                    Ok(ExitCode::SUCCESS)
+                }
+                Err(err) => {
+                    let (line, column) = line_column(&text[..err.position]);
+                    eprintln!(
+                        "character {:?} can not be encoded in {to} (line {line}, character {column})",
+                        err.char
+                    );
+                    Ok(ExitCode::FAILURE)
+                }
+            }
+        }
+        Command::Detect { file } => {
+            let bytes = read(file.as_deref())?;
+            for guess in detect::detect(&bytes) {
+                println!("{}: {}", guess.encoding, guess.reason);
+            }
+            Ok(ExitCode::SUCCESS)
+        }
+    }
+}
+
+/// Reads `file`, or the standard input if `None`.
+fn read(file: Option<&str>) -> io::Result<Vec<u8>> {
+    match file {
+        Some(file) => fs::read(file),
+        None => {
+            let mut bytes = Vec::new();
+            io::stdin().read_to_end(&mut bytes)?;
+            Ok(bytes)
+        }
+    }
+}
+
+/// Returns the line and column, counted in characters, of the position following `before`.
+fn line_column(before: &str) -> (usize, usize) {
+    let line = before.matches('\n').count() + 1;
+    let column = before
+        .chars()
+        .rev()
+        .take_while(|&char| char != '\n')
+        .count()
+        + 1;
+    (line, column)
+}
+
+/// Same as [`line_column`], for the bytes in `encoding` before an invalid byte, which are valid.
+fn decoded_line_column(before: &[u8], encoding: Encoding) -> (usize, usize) {
+    let before = codec::decode(before, encoding, Mode::Lossy).unwrap_or_default();
+    line_column(&before)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Command, String> {
+        let args: Vec<String> = args.iter().map(|arg| arg.to_string()).collect();
+        Command::parse(&args)
+    }
+
+    #[test]
+    fn parse_commands() {
+        assert_eq!(
+            parse(&["validate", "latin1", "in.txt"]),
+            Ok(Command::Validate {
+                encoding: Encoding::Latin1,
+                file: Some("in.txt".to_owned()),
+            })
+        );
+        assert_eq!(
+            parse(&["transcode", "cp1252", "utf-8", "--mode", "lossy", "-"]),
+            Ok(Command::Transcode {
+                from: Encoding::Cp1252,
+                to: Encoding::Utf8,
+                mode: Mode::Lossy,
+                file: None,
+            })
+        );
+        assert_eq!(
+            parse(&["transcode", "utf-8", "cp1252"]),
+            Ok(Command::Transcode {
+                from: Encoding::Utf8,
+                to: Encoding::Cp1252,
+                mode: Mode::Strict,
+                file: None,
+            })
+        );
+        assert_eq!(parse(&["detect"]), Ok(Command::Detect { file: None }));
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert!(parse(&[]).is_err());
+        assert!(parse(&["convert"]).is_err());
+        assert!(parse(&["validate"]).is_err());
+        assert!(parse(&["validate", "utf-8", "a", "b"]).is_err());
+        assert!(parse(&["detect", "--mode"]).is_err());
+        assert!(parse(&["detect", "--verbose"]).is_err());
+    }
+
+    #[test]
+    fn positions() {
+        assert_eq!(line_column(""), (1, 1));
+        assert_eq!(line_column("ab"), (1, 3));
+        assert_eq!(line_column("ab\ncd\nx"), (3, 2));
+        assert_eq!(line_column("\nçà"), (2, 3));
+
+        assert_eq!(
+            decoded_line_column("l1\nçà".as_bytes(), Encoding::Utf8),
+            (2, 3)
+        );
+        assert_eq!(
+            decoded_line_column(b"l1\n\xE7\xE0", Encoding::Latin1),
+            (2, 3)
+        );
+        assert_eq!(
+            decoded_line_column(b"\n\x1B\x65\x1B\x65", Encoding::Gsm0338),
+            (2, 3)
+        );
+    }
+}
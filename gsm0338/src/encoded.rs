@@ -0,0 +1,99 @@
+//! [`EncodedStr`] implementations.
+//!
+//! [`Gsm7Str`] and [`Gsm7String`] can be stored as `&dyn EncodedStr` or `Box<dyn EncodedStr>`
+//! alongside the strings of the `iso8859-1` crate. Their bytes are the unpacked septets, so their
+//! length is in septets and a character of the extension table takes two of them.
+
+use iso8859_1::EncodedStr;
+
+use crate::{Gsm7Str, Gsm7String};
+
+impl EncodedStr for Gsm7Str {
+    #[inline]
+    fn len(&self) -> usize {
+        self.len()
+    }
+
+    #[inline]
+    fn as_bytes(&self) -> &[u8] {
+        self.as_septets()
+    }
+
+    fn to_utf8(&self) -> String {
+        self.to_string()
+    }
+
+    /// Returns `"GSM 03.38"`, as the encoding is not registered by IANA.
+    #[inline]
+    fn encoding_name(&self) -> &'static str {
+        "GSM 03.38"
+    }
+
+    fn char_at(&self, index: usize) -> Option<char> {
+        self.chars().nth(index).map(char::from)
+    }
+}
+
+impl EncodedStr for Gsm7String {
+    #[inline]
+    fn len(&self) -> usize {
+        self.as_str().len()
+    }
+
+    #[inline]
+    fn as_bytes(&self) -> &[u8] {
+        self.as_str().as_septets()
+    }
+
+    fn to_utf8(&self) -> String {
+        EncodedStr::to_utf8(self.as_str())
+    }
+
+    #[inline]
+    fn encoding_name(&self) -> &'static str {
+        EncodedStr::encoding_name(self.as_str())
+    }
+
+    fn char_at(&self, index: usize) -> Option<char> {
+        EncodedStr::char_at(self.as_str(), index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use iso8859_1::IsoLatin1String;
+
+    use super::*;
+
+    #[test]
+    fn trait_objects() {
+        let s = Gsm7Str::from_septets(b"\x1B\x65\x35").unwrap();
+        let owned = s.to_owned();
+        let strings: [&dyn EncodedStr; 2] = [&s, &owned];
+
+        for s in strings {
+            assert_eq!(s.len(), 3);
+            assert!(!s.is_empty());
+            assert_eq!(s.as_bytes(), b"\x1B\x65\x35");
+            assert_eq!(s.to_utf8(), "€5");
+            assert_eq!(s.encoding_name(), "GSM 03.38");
+            assert_eq!(s.char_at(0), Some('€'));
+            assert_eq!(s.char_at(1), Some('5'));
+            assert_eq!(s.char_at(2), None);
+        }
+    }
+
+    #[test]
+    fn mixed_encodings() {
+        let gsm: Gsm7String = "Olé".parse().unwrap();
+        let latin1 = IsoLatin1String::from_str_lossy("Olé");
+        let strings: Vec<Box<dyn EncodedStr>> = vec![Box::new(gsm), Box::new(latin1)];
+
+        for s in &strings {
+            assert_eq!(s.to_utf8(), "Olé");
+            assert_eq!(s.char_at(2), Some('é'));
+        }
+        assert_eq!(strings[0].as_bytes(), b"\x4F\x6C\x05");
+        assert_eq!(strings[1].as_bytes(), b"Ol\xE9");
+    }
+}
@@ -0,0 +1,92 @@
+//! Iterators over [`Gsm7Str`].
+
+use std::{iter::FusedIterator, slice};
+
+use crate::{table, Gsm7Char, Gsm7Str};
+
+/// An iterator over the [`Gsm7Char`]s of a string slice.
+///
+/// This struct is created by the [`chars`] method on [`Gsm7Str`]. See its documentation for more.
+///
+/// [`chars`]: Gsm7Str::chars
+#[derive(Clone, Debug)]
+pub struct Chars<'a> {
+    iter: slice::Iter<'a, u8>,
+}
+
+impl<'a> Chars<'a> {
+    pub(crate) fn new(s: &'a Gsm7Str) -> Self {
+        Chars {
+            iter: s.as_septets().iter(),
+        }
+    }
+
+    /// Views the underlying data as a subslice of the original data.
+    pub fn as_str(&self) -> &'a Gsm7Str {
+        // SAFETY: the remaining septets are a subslice of a valid `Gsm7Str`, and never start in
+        // the middle of an escape sequence.
+        unsafe { Gsm7Str::from_septets_unchecked(self.iter.as_slice()) }
+    }
+}
+
+impl Iterator for Chars<'_> {
+    type Item = Gsm7Char;
+
+    #[inline]
+    fn next(&mut self) -> Option<Gsm7Char> {
+        let septet = *self.iter.next()?;
+        if septet == table::ESCAPE {
+            let code = *self.iter.next()?;
+            Some(Gsm7Char::extension(code))
+        } else {
+            Some(Gsm7Char::basic(septet))
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.iter.len();
+        ((len + 1) / 2, Some(len))
+    }
+}
+
+impl DoubleEndedIterator for Chars<'_> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Gsm7Char> {
+        let septet = *self.iter.next_back()?;
+
+        // No extension code is an escape, so an escape before the septet always starts its
+        // escape sequence.
+        if self.iter.as_slice().last() == Some(&table::ESCAPE) {
+            self.iter.next_back();
+            Some(Gsm7Char::extension(septet))
+        } else {
+            Some(Gsm7Char::basic(septet))
+        }
+    }
+}
+
+impl FusedIterator for Chars<'_> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::Gsm7Str;
+
+    #[test]
+    fn chars() {
+        let s = Gsm7Str::from_septets(b"a\x1B\x65\x1B\x14b").unwrap();
+        let chars: Vec<char> = s.chars().map(char::from).collect();
+        assert_eq!(chars, ['a', '€', '^', 'b']);
+
+        let mut chars = s.chars();
+        chars.next();
+        assert_eq!(chars.as_str().as_septets(), b"\x1B\x65\x1B\x14b");
+    }
+
+    #[test]
+    fn chars_rev() {
+        let s = Gsm7Str::from_septets(b"\x1B\x14\x1B\x65a\x1B\x28\x1B\x29").unwrap();
+        let chars: Vec<char> = s.chars().rev().map(char::from).collect();
+        assert_eq!(chars, ['}', '{', 'a', '€', '^']);
+    }
+}
@@ -0,0 +1,669 @@
+//! # GSM 03.38 String Library
+//!
+//! This crate provides string and character types that are encoded in the GSM 03.38 default
+//! alphabet, the 7-bit encoding of SMS messages.
+//!
+//! Text is kept unpacked, one septet per byte. A few characters, like `€` and `{`, are not in the
+//! default alphabet but in its extension table: they are encoded as two septets, the escape
+//! (`0x1B`) followed by their code. The [`packing`] module converts septets from and into the
+//! packed form of SMS messages.
+//!
+//! # Examples
+//!
+//! Basic usage:
+//!
+//! ```
+//! use gsm0338::Gsm7String;
+//!
+//! let s = "Olá, {Δ} 5€".parse::<Gsm7String>().unwrap_err();
+//! assert_eq!(s.char(), 'á');
+//!
+//! let s: Gsm7String = "Hej, {Δ} 5€".parse().unwrap();
+//! assert_eq!(s.len(), 14);
+//! assert_eq!(s.chars().count(), 11);
+//! assert_eq!(s.to_string(), "Hej, {Δ} 5€");
+//!
+//! let packed = s.pack();
+//! assert_eq!(packed.len(), 13);
+//! assert_eq!(Gsm7String::unpack(&packed, s.len()).unwrap(), s);
+//! ```
+
+use std::{borrow::Borrow, error::Error, fmt, mem, ops, str::FromStr};
+
+mod encoded;
+mod iter;
+pub mod packing;
+mod table;
+
+pub use iter::Chars;
+
+/// A character of the GSM 03.38 default alphabet or of its extension table.
+///
+/// Characters of the extension table take two septets in a string, see
+/// [`is_extension`](Gsm7Char::is_extension).
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Gsm7Char(u8);
+
+/// Set on the value of a [`Gsm7Char`] of the extension table, whose code is in the lower bits.
+const EXTENSION_BIT: u8 = 0x80;
+
+impl Gsm7Char {
+    pub(crate) fn basic(septet: u8) -> Self {
+        Gsm7Char(septet)
+    }
+
+    pub(crate) fn extension(code: u8) -> Self {
+        Gsm7Char(code | EXTENSION_BIT)
+    }
+
+    /// Returns `true` if this character is in the extension table, and so is encoded as the
+    /// escape septet followed by its [`code`](Gsm7Char::code).
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use gsm0338::Gsm7Char;
+    ///
+    /// assert!(!Gsm7Char::try_from('Ø').unwrap().is_extension());
+    /// assert!(Gsm7Char::try_from('€').unwrap().is_extension());
+    /// ```
+    #[inline]
+    pub fn is_extension(&self) -> bool {
+        self.0 & EXTENSION_BIT != 0
+    }
+
+    /// Returns the septet of this character in its table.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use gsm0338::Gsm7Char;
+    ///
+    /// assert_eq!(Gsm7Char::try_from('@').unwrap().code(), 0x00);
+    /// assert_eq!(Gsm7Char::try_from('€').unwrap().code(), 0x65);
+    /// ```
+    #[inline]
+    pub fn code(&self) -> u8 {
+        self.0 & !EXTENSION_BIT
+    }
+
+    /// Returns the number of septets needed to encode this character, `1` or `2`.
+    #[inline]
+    pub fn len_septets(&self) -> usize {
+        if self.is_extension() {
+            2
+        } else {
+            1
+        }
+    }
+}
+
+impl fmt::Debug for Gsm7Char {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&char::from(*self), f)
+    }
+}
+
+impl fmt::Display for Gsm7Char {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&char::from(*self), f)
+    }
+}
+
+impl TryFrom<char> for Gsm7Char {
+    type Error = Gsm7CharError;
+
+    #[inline]
+    fn try_from(char: char) -> Result<Self, Self::Error> {
+        match table::encode(char) {
+            Some((code, true)) => Ok(Gsm7Char::extension(code)),
+            Some((septet, false)) => Ok(Gsm7Char::basic(septet)),
+            None => Err(Gsm7CharError { char }),
+        }
+    }
+}
+
+impl From<Gsm7Char> for char {
+    #[inline]
+    fn from(char: Gsm7Char) -> char {
+        if char.is_extension() {
+            // Only built from codes of the extension table.
+            table::decode_extension(char.code()).unwrap_or(char::REPLACEMENT_CHARACTER)
+        } else {
+            table::decode_basic(char.code())
+        }
+    }
+}
+
+/// The error returned when a [`char`] is not in GSM 03.38.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Gsm7CharError {
+    char: char,
+}
+
+impl Gsm7CharError {
+    /// Returns the character that could not be encoded.
+    pub fn char(&self) -> char {
+        self.char
+    }
+}
+
+impl fmt::Display for Gsm7CharError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "character {:?} is not in GSM 03.38", self.char)
+    }
+}
+
+impl Error for Gsm7CharError {}
+
+/// A GSM 03.38 string slice, as unpacked septets.
+#[derive(PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct Gsm7Str {
+    septets: [u8],
+}
+
+impl Gsm7Str {
+    /// Converts a slice of unpacked septets to a `Gsm7Str`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] if a byte is not a septet, or if an escape is not followed by a code of the
+    /// extension table, with the position of the first invalid septet.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use gsm0338::{Gsm7ErrorKind, Gsm7Str};
+    ///
+    /// let s = Gsm7Str::from_septets(b"\x1B\x65 10").unwrap();
+    /// assert_eq!(s, "€ 10");
+    ///
+    /// let err = Gsm7Str::from_septets(b"10 \x1B").unwrap_err();
+    /// assert_eq!(err.valid_up_to(), 3);
+    /// assert_eq!(err.kind(), Gsm7ErrorKind::IncompleteEscape);
+    /// ```
+    pub fn from_septets(septets: &[u8]) -> Result<&Self, Gsm7Error> {
+        validate(septets)?;
+
+        // SAFETY: just validated.
+        Ok(unsafe { Gsm7Str::from_septets_unchecked(septets) })
+    }
+
+    /// Converts a slice of unpacked septets to a `Gsm7Str` without checking that it is valid.
+    ///
+    /// # Safety
+    ///
+    /// Every byte must be a septet (below `0x80`), and every escape (`0x1B`) must be followed by
+    /// a code of the extension table.
+    pub const unsafe fn from_septets_unchecked(septets: &[u8]) -> &Self {
+        // SAFETY: `Gsm7Str` is `repr(transparent)` over `[u8]`.
+        mem::transmute::<&[u8], &Gsm7Str>(septets)
+    }
+
+    /// Returns the length of `self`, in septets.
+    ///
+    /// Characters of the extension table take two septets, so this may be more than the number
+    /// of characters.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.septets.len()
+    }
+
+    /// Returns `true` if `self` has a length of zero septets.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.septets.is_empty()
+    }
+
+    /// Returns the unpacked septets of this string slice.
+    #[inline]
+    pub fn as_septets(&self) -> &[u8] {
+        &self.septets
+    }
+
+    /// Returns an iterator over the [`Gsm7Char`]s of a string slice.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use gsm0338::Gsm7Str;
+    ///
+    /// let s = Gsm7Str::from_septets(b"\x10\x1B\x3C").unwrap();
+    /// let mut chars = s.chars();
+    ///
+    /// assert_eq!(chars.next().map(char::from), Some('Δ'));
+    /// assert_eq!(chars.next().map(char::from), Some('['));
+    /// assert_eq!(chars.next(), None);
+    /// ```
+    #[inline]
+    pub fn chars(&self) -> Chars<'_> {
+        Chars::new(self)
+    }
+
+    /// Packs the septets of this string slice, as done in SMS messages.
+    ///
+    /// See the [`packing`] module.
+    pub fn pack(&self) -> Vec<u8> {
+        packing::pack(&self.septets)
+    }
+}
+
+/// Checks that `septets` is valid GSM 03.38.
+fn validate(septets: &[u8]) -> Result<(), Gsm7Error> {
+    let error = |valid_up_to, kind| Err(Gsm7Error { valid_up_to, kind });
+
+    let mut index = 0;
+    while index < septets.len() {
+        match septets[index] {
+            table::ESCAPE => match septets.get(index + 1) {
+                Some(&code) if table::decode_extension(code).is_some() => index += 2,
+                Some(_) => return error(index, Gsm7ErrorKind::UnknownExtension),
+                None => return error(index, Gsm7ErrorKind::IncompleteEscape),
+            },
+            0x80..=0xFF => return error(index, Gsm7ErrorKind::NotASeptet),
+            _ => index += 1,
+        }
+    }
+    Ok(())
+}
+
+impl AsRef<Gsm7Str> for Gsm7Str {
+    #[inline]
+    fn as_ref(&self) -> &Gsm7Str {
+        self
+    }
+}
+
+impl Default for &Gsm7Str {
+    #[inline]
+    fn default() -> Self {
+        // SAFETY: an empty slice is valid.
+        unsafe { Gsm7Str::from_septets_unchecked(&[]) }
+    }
+}
+
+impl ToOwned for Gsm7Str {
+    type Owned = Gsm7String;
+
+    #[inline]
+    fn to_owned(&self) -> Gsm7String {
+        Gsm7String {
+            septets: self.septets.to_vec(),
+        }
+    }
+}
+
+impl fmt::Debug for Gsm7Str {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("\"")?;
+        fmt::Display::fmt(self, f)?;
+        f.write_str("\"")
+    }
+}
+
+impl fmt::Display for Gsm7Str {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for char in self.chars() {
+            fmt::Write::write_char(f, char.into())?;
+        }
+        Ok(())
+    }
+}
+
+impl PartialEq<str> for Gsm7Str {
+    fn eq(&self, other: &str) -> bool {
+        self.chars().map(char::from).eq(other.chars())
+    }
+}
+
+impl PartialEq<&str> for Gsm7Str {
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        self == *other
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for &'a Gsm7Str {
+    type Error = Gsm7Error;
+
+    #[inline]
+    fn try_from(septets: &'a [u8]) -> Result<Self, Self::Error> {
+        Gsm7Str::from_septets(septets)
+    }
+}
+
+/// The reason for a sequence of septets not being valid GSM 03.38.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Gsm7ErrorKind {
+    /// A byte has its high bit set.
+    NotASeptet,
+    /// An escape is followed by a septet that is not in the extension table.
+    UnknownExtension,
+    /// An escape is the last septet.
+    IncompleteEscape,
+}
+
+/// Errors which can occur when attempting to interpret a sequence of septets as a [`Gsm7Str`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Gsm7Error {
+    valid_up_to: usize,
+    kind: Gsm7ErrorKind,
+}
+
+impl Gsm7Error {
+    /// Returns the index in the given septets up to which valid GSM 03.38 was verified, which is
+    /// also the index of the offending septet or escape.
+    pub fn valid_up_to(&self) -> usize {
+        self.valid_up_to
+    }
+
+    /// Returns the reason of the error.
+    pub fn kind(&self) -> Gsm7ErrorKind {
+        self.kind
+    }
+}
+
+impl fmt::Display for Gsm7Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = match self.kind {
+            Gsm7ErrorKind::NotASeptet => "byte is not a septet",
+            Gsm7ErrorKind::UnknownExtension => "escape to unknown extension character",
+            Gsm7ErrorKind::IncompleteEscape => "incomplete escape",
+        };
+        write!(f, "{} at index {}", message, self.valid_up_to)
+    }
+}
+
+impl Error for Gsm7Error {}
+
+/// A GSM 03.38 owned string, as unpacked septets.
+#[derive(PartialEq, Eq, Hash, Clone, Default)]
+pub struct Gsm7String {
+    septets: Vec<u8>,
+}
+
+impl Gsm7String {
+    /// Creates a new empty `Gsm7String`.
+    #[inline]
+    pub const fn new() -> Self {
+        Gsm7String {
+            septets: Vec::new(),
+        }
+    }
+
+    /// Converts a vector of unpacked septets to a `Gsm7String`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] for the same reasons as [`Gsm7Str::from_septets`].
+    pub fn from_septets(septets: Vec<u8>) -> Result<Self, Gsm7Error> {
+        validate(&septets)?;
+        Ok(Gsm7String { septets })
+    }
+
+    /// Unpacks `count` septets from the packed form of SMS messages.
+    ///
+    /// See the [`packing`] module.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] if the unpacked septets are not valid GSM 03.38.
+    pub fn unpack(octets: &[u8], count: usize) -> Result<Self, Gsm7Error> {
+        Gsm7String::from_septets(packing::unpack(octets, count))
+    }
+
+    /// Converts a `Gsm7String` into its unpacked septets.
+    #[inline]
+    pub fn into_septets(self) -> Vec<u8> {
+        self.septets
+    }
+
+    /// Extracts a string slice containing the entire `Gsm7String`.
+    #[inline]
+    pub fn as_str(&self) -> &Gsm7Str {
+        // SAFETY: the septets of a `Gsm7String` are always valid.
+        unsafe { Gsm7Str::from_septets_unchecked(&self.septets) }
+    }
+
+    /// Appends the given character to the end of this `Gsm7String`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use gsm0338::{Gsm7Char, Gsm7String};
+    ///
+    /// let mut s = Gsm7String::new();
+    /// s.push(Gsm7Char::try_from('a').unwrap());
+    /// s.push(Gsm7Char::try_from('~').unwrap());
+    ///
+    /// assert_eq!(s.as_septets(), b"a\x1B\x3D");
+    /// ```
+    pub fn push(&mut self, char: Gsm7Char) {
+        if char.is_extension() {
+            self.septets.push(table::ESCAPE);
+        }
+        self.septets.push(char.code());
+    }
+
+    /// Appends the given string slice to the end of this `Gsm7String`.
+    #[inline]
+    pub fn push_str(&mut self, s: &Gsm7Str) {
+        self.septets.extend_from_slice(s.as_septets());
+    }
+}
+
+impl ops::Deref for Gsm7String {
+    type Target = Gsm7Str;
+
+    #[inline]
+    fn deref(&self) -> &Gsm7Str {
+        self.as_str()
+    }
+}
+
+impl AsRef<Gsm7Str> for Gsm7String {
+    #[inline]
+    fn as_ref(&self) -> &Gsm7Str {
+        self
+    }
+}
+
+impl Borrow<Gsm7Str> for Gsm7String {
+    #[inline]
+    fn borrow(&self) -> &Gsm7Str {
+        self
+    }
+}
+
+impl fmt::Debug for Gsm7String {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl fmt::Display for Gsm7String {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.as_str(), f)
+    }
+}
+
+impl PartialEq<str> for Gsm7String {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for Gsm7String {
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl Extend<Gsm7Char> for Gsm7String {
+    fn extend<I: IntoIterator<Item = Gsm7Char>>(&mut self, iter: I) {
+        for char in iter {
+            self.push(char);
+        }
+    }
+}
+
+impl FromIterator<Gsm7Char> for Gsm7String {
+    fn from_iter<I: IntoIterator<Item = Gsm7Char>>(iter: I) -> Self {
+        let mut string = Gsm7String::new();
+        string.extend(iter);
+        string
+    }
+}
+
+impl FromStr for Gsm7String {
+    type Err = Gsm7CharError;
+
+    /// Encodes a UTF-8 string slice.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] with the first character that is not in GSM 03.38.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut string = Gsm7String::new();
+        for char in s.chars() {
+            string.push(Gsm7Char::try_from(char)?);
+        }
+        Ok(string)
+    }
+}
+
+impl TryFrom<&str> for Gsm7String {
+    type Error = Gsm7CharError;
+
+    #[inline]
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+#[cfg(test)]
+mod char_tests {
+    use super::*;
+
+    #[test]
+    fn try_from_char() {
+        let char = Gsm7Char::try_from('Ω').unwrap();
+        assert_eq!(char.code(), 0x15);
+        assert!(!char.is_extension());
+        assert_eq!(char.len_septets(), 1);
+        assert_eq!(char::from(char), 'Ω');
+
+        let char = Gsm7Char::try_from('\\').unwrap();
+        assert_eq!(char.code(), 0x2F);
+        assert!(char.is_extension());
+        assert_eq!(char.len_septets(), 2);
+        assert_eq!(char::from(char), '\\');
+
+        let err = Gsm7Char::try_from('ê').unwrap_err();
+        assert_eq!(err.char(), 'ê');
+        assert_eq!(err.to_string(), "character 'ê' is not in GSM 03.38");
+    }
+
+    #[test]
+    fn fmt() {
+        let char = Gsm7Char::try_from('€').unwrap();
+        assert_eq!(format!("{char}"), "€");
+        assert_eq!(format!("{char:?}"), "'€'");
+    }
+}
+
+#[cfg(test)]
+mod str_tests {
+    use super::*;
+
+    #[test]
+    fn from_septets() {
+        let s = Gsm7Str::from_septets(b"\x00\x1B\x40\x7F").unwrap();
+        assert_eq!(s.as_septets(), b"\x00\x1B\x40\x7F");
+        assert_eq!(s.len(), 4);
+        assert_eq!(s, "@|à");
+
+        let err = Gsm7Str::from_septets(b"ab\x80").unwrap_err();
+        assert_eq!(err.valid_up_to(), 2);
+        assert_eq!(err.kind(), Gsm7ErrorKind::NotASeptet);
+
+        let err = Gsm7Str::from_septets(b"a\x1B\x1B\x65").unwrap_err();
+        assert_eq!(err.valid_up_to(), 1);
+        assert_eq!(err.kind(), Gsm7ErrorKind::UnknownExtension);
+        assert_eq!(
+            err.to_string(),
+            "escape to unknown extension character at index 1"
+        );
+    }
+
+    #[test]
+    fn eq() {
+        let s = Gsm7Str::from_septets(b"\x1B\x28\x1B\x29").unwrap();
+        assert_eq!(s, "{}");
+        assert_ne!(s, "{");
+        assert_ne!(s, "{}}");
+        assert!(<&Gsm7Str>::default().is_empty());
+    }
+
+    #[test]
+    fn fmt() {
+        let s = Gsm7Str::from_septets(b"\x1E\x0E").unwrap();
+        assert_eq!(format!("{s}"), "ßÅ");
+        assert_eq!(format!("{s:?}"), "\"ßÅ\"");
+    }
+}
+
+#[cfg(test)]
+mod string_tests {
+    use super::*;
+
+    #[test]
+    fn from_str() {
+        let s: Gsm7String = "[£5]".parse().unwrap();
+        assert_eq!(s.as_septets(), b"\x1B\x3C\x015\x1B\x3E");
+        assert_eq!(Gsm7String::try_from("[£5]").unwrap(), s);
+
+        assert_eq!("a`".parse::<Gsm7String>().unwrap_err().char(), '`');
+        assert_eq!(Gsm7String::try_from("[£5]").unwrap(), "[£5]");
+    }
+
+    #[test]
+    fn pack_unpack() {
+        let s: Gsm7String = "hellohello".parse().unwrap();
+        let packed = s.pack();
+        assert_eq!(
+            packed,
+            [0xE8, 0x32, 0x9B, 0xFD, 0x46, 0x97, 0xD9, 0xEC, 0x37]
+        );
+        assert_eq!(Gsm7String::unpack(&packed, 10).unwrap(), "hellohello");
+
+        let err = Gsm7String::unpack(&packing::pack(b"a\x1B"), 2).unwrap_err();
+        assert_eq!(err.kind(), Gsm7ErrorKind::IncompleteEscape);
+    }
+
+    #[test]
+    fn push_and_collect() {
+        let mut s: Gsm7String = "Ab€"
+            .chars()
+            .map(|c| Gsm7Char::try_from(c).unwrap())
+            .collect();
+        s.push_str(Gsm7Str::from_septets(b"\x1B\x14").unwrap());
+        assert_eq!(s, "Ab€^");
+        assert_eq!(s.clone().into_septets(), b"Ab\x1B\x65\x1B\x14");
+        assert_eq!(s.to_string(), "Ab€^");
+    }
+}
@@ -0,0 +1,99 @@
+//! Packing of septets into octets.
+//!
+//! SMS messages carry GSM 03.38 text packed: the 7 bits of every septet follow each other, the
+//! first septet in the least significant bits of the first octet, so 8 septets fit in 7 octets.
+//!
+//! As the last octet may have room for one more septet, the number of septets, given by the user
+//! data length of the message, is needed to unpack them.
+//!
+//! # Examples
+//!
+//! Basic usage:
+//!
+//! ```
+//! use gsm0338::packing;
+//!
+//! let packed = packing::pack(b"hellohello");
+//! assert_eq!(packed, [0xE8, 0x32, 0x9B, 0xFD, 0x46, 0x97, 0xD9, 0xEC, 0x37]);
+//!
+//! assert_eq!(packing::unpack(&packed, 10), b"hellohello");
+//! ```
+
+/// Returns the number of octets needed to pack `septets` septets.
+#[inline]
+pub fn packed_len(septets: usize) -> usize {
+    (septets * 7 + 7) / 8
+}
+
+/// Packs `septets` into octets.
+///
+/// Only the 7 lower bits of every septet are used.
+pub fn pack(septets: &[u8]) -> Vec<u8> {
+    let mut octets = vec![0; packed_len(septets.len())];
+    for (index, &septet) in septets.iter().enumerate() {
+        let bit = index * 7;
+        let bits = u16::from(septet & 0x7F) << (bit % 8);
+
+        octets[bit / 8] |= bits as u8;
+        if bit % 8 > 1 {
+            octets[bit / 8 + 1] |= (bits >> 8) as u8;
+        }
+    }
+    octets
+}
+
+/// Unpacks `count` septets from `octets`.
+///
+/// Fewer septets are returned if `octets` is too short to hold `count` of them.
+pub fn unpack(octets: &[u8], count: usize) -> Vec<u8> {
+    let count = count.min(octets.len() * 8 / 7);
+    (0..count)
+        .map(|index| {
+            let bit = index * 7;
+            let mut bits = u16::from(octets[bit / 8]) >> (bit % 8);
+            if bit % 8 > 1 {
+                bits |= u16::from(octets[bit / 8 + 1]) << (8 - bit % 8);
+            }
+            bits as u8 & 0x7F
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lengths() {
+        assert_eq!(packed_len(0), 0);
+        assert_eq!(packed_len(1), 1);
+        assert_eq!(packed_len(7), 7);
+        assert_eq!(packed_len(8), 7);
+        assert_eq!(packed_len(9), 8);
+        assert_eq!(packed_len(160), 140);
+    }
+
+    #[test]
+    fn round_trip() {
+        let septets: Vec<u8> = (0..=0x7F).collect();
+        for len in 0..septets.len() {
+            let packed = pack(&septets[..len]);
+            assert_eq!(packed.len(), packed_len(len));
+            assert_eq!(unpack(&packed, len), &septets[..len], "{len}");
+        }
+    }
+
+    #[test]
+    fn unpack_short() {
+        // 7 septets leave room for an eighth one, read as `@` (`0x00`) if asked for.
+        let packed = pack(b"1234567");
+        assert_eq!(unpack(&packed, 8), b"1234567\0");
+        assert_eq!(unpack(&packed, 9), b"1234567\0");
+        assert_eq!(unpack(&[], 1), b"");
+    }
+
+    #[test]
+    fn pack_ignores_high_bit() {
+        assert_eq!(pack(&[0xC1]), pack(&[0x41]));
+    }
+}
@@ -0,0 +1,86 @@
+//! The GSM 03.38 default alphabet and its extension table.
+
+/// The septet that escapes to the extension table.
+pub(crate) const ESCAPE: u8 = 0x1B;
+
+/// The characters of the default alphabet, indexed by septet.
+///
+/// The escape septet `0x1B` is never decoded on its own, its entry is a placeholder.
+const BASIC: [char; 128] = [
+    '@', '£', '$', '¥', 'è', 'é', 'ù', 'ì', 'ò', 'Ç', '\n', 'Ø', 'ø', '\r', 'Å', 'å', //
+    'Δ', '_', 'Φ', 'Γ', 'Λ', 'Ω', 'Π', 'Ψ', 'Σ', 'Θ', 'Ξ', '\u{A0}', 'Æ', 'æ', 'ß', 'É', //
+    ' ', '!', '"', '#', '¤', '%', '&', '\'', '(', ')', '*', '+', ',', '-', '.', '/', //
+    '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', ':', ';', '<', '=', '>', '?', //
+    '¡', 'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'I', 'J', 'K', 'L', 'M', 'N', 'O', //
+    'P', 'Q', 'R', 'S', 'T', 'U', 'V', 'W', 'X', 'Y', 'Z', 'Ä', 'Ö', 'Ñ', 'Ü', '§', //
+    '¿', 'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', //
+    'p', 'q', 'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z', 'ä', 'ö', 'ñ', 'ü', 'à', //
+];
+
+/// The characters of the extension table, with the septet following the escape.
+const EXTENSION: [(u8, char); 10] = [
+    (0x0A, '\u{C}'),
+    (0x14, '^'),
+    (0x28, '{'),
+    (0x29, '}'),
+    (0x2F, '\\'),
+    (0x3C, '['),
+    (0x3D, '~'),
+    (0x3E, ']'),
+    (0x40, '|'),
+    (0x65, '€'),
+];
+
+/// Decodes a septet of the default alphabet, other than the escape.
+pub(crate) fn decode_basic(septet: u8) -> char {
+    BASIC[usize::from(septet)]
+}
+
+/// Decodes the septet following an escape, if it is in the extension table.
+pub(crate) fn decode_extension(septet: u8) -> Option<char> {
+    EXTENSION
+        .iter()
+        .find(|(code, _)| *code == septet)
+        .map(|&(_, char)| char)
+}
+
+/// Encodes a character, returning its septet and whether it is in the extension table.
+pub(crate) fn encode(char: char) -> Option<(u8, bool)> {
+    if let Some(septet) = BASIC.iter().position(|&c| c == char) {
+        if septet != usize::from(ESCAPE) {
+            return Some((septet as u8, false));
+        }
+    }
+    EXTENSION
+        .iter()
+        .find(|(_, c)| *c == char)
+        .map(|&(septet, _)| (septet, true))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        for septet in (0x00..=0x7F).filter(|&septet| septet != ESCAPE) {
+            assert_eq!(
+                encode(decode_basic(septet)),
+                Some((septet, false)),
+                "0x{septet:x}"
+            );
+        }
+        for (septet, char) in EXTENSION {
+            assert_eq!(decode_extension(septet), Some(char));
+            assert_eq!(encode(char), Some((septet, true)), "{char:?}");
+        }
+    }
+
+    #[test]
+    fn unencodable() {
+        assert_eq!(encode('\u{A0}'), None);
+        assert_eq!(encode('`'), None);
+        assert_eq!(encode('ê'), None);
+        assert_eq!(decode_extension(0x41), None);
+    }
+}
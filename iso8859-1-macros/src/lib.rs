@@ -0,0 +1,153 @@
+//! # ISO8859-1 String Library Macros
+//!
+//! Procedural macros for the `iso8859-1` crate. Don't depend on this crate directly, use the
+//! re-exports of `iso8859-1` instead, as the expansions refer to it by the `iso8859_1` name.
+
+use proc_macro::{Delimiter, Group, Ident, Literal, Punct, Spacing, Span, TokenStream, TokenTree};
+
+/// Creates a `&'static IsoLatin1Str` from a string literal, validated and encoded at compile time.
+///
+/// See the documentation of the re-export in `iso8859-1` for more.
+#[proc_macro]
+pub fn latin1(input: TokenStream) -> TokenStream {
+    match expand(input) {
+        Ok(tokens) => tokens,
+        Err((message, span)) => compile_error(&message, span),
+    }
+}
+
+type Error = (String, Span);
+
+fn expand(input: TokenStream) -> Result<TokenStream, Error> {
+    let literal = single_literal(input)?;
+    let span = literal.span();
+
+    let text = parse_str_literal(&literal.to_string())
+        .ok_or_else(|| ("expected a string literal".to_string(), span))?;
+
+    let bytes = encode(&text).map_err(|char| {
+        let message = format!("character {char:?} can not be encoded in ISO8859-1");
+        (message, span)
+    })?;
+
+    // Going through a constant evaluates the checked constructor at compile time, so the
+    // expansion needs no `unsafe`.
+    let expansion = format!(
+        "{{ const S: &::iso8859_1::IsoLatin1Str = \
+         ::iso8859_1::IsoLatin1Str::from_iso8859_1_const({}); S }}",
+        Literal::byte_string(&bytes)
+    );
+
+    Ok(expansion.parse().expect("the expansion is valid Rust"))
+}
+
+/// Extracts the only literal of `input`, looking through the invisible groups `macro_rules!`
+/// creates around `$lit:literal` fragments.
+fn single_literal(input: TokenStream) -> Result<Literal, Error> {
+    let mut tokens = input.into_iter();
+
+    let literal = match (tokens.next(), tokens.next()) {
+        (Some(TokenTree::Literal(literal)), None) => literal,
+        (Some(TokenTree::Group(group)), None) if group.delimiter() == Delimiter::None => {
+            return single_literal(group.stream());
+        }
+        (Some(token), _) => return Err(("expected a string literal".to_string(), token.span())),
+        (None, _) => {
+            let message = "expected a string literal, found nothing".to_string();
+            return Err((message, Span::call_site()));
+        }
+    };
+
+    Ok(literal)
+}
+
+/// Returns the value of the string literal `source`, or `None` if it is not a string literal.
+fn parse_str_literal(source: &str) -> Option<String> {
+    if let Some(raw) = source.strip_prefix('r') {
+        let hashes = raw.len() - raw.trim_start_matches('#').len();
+        let content = raw[hashes..].strip_prefix('"')?;
+        let content = content.strip_suffix(&raw[..hashes])?.strip_suffix('"')?;
+        return Some(content.to_string());
+    }
+
+    let content = source.strip_prefix('"')?.strip_suffix('"')?;
+    unescape(content)
+}
+
+/// Resolves the escape sequences of the content of a (non-raw) string literal.
+fn unescape(content: &str) -> Option<String> {
+    let mut text = String::with_capacity(content.len());
+    let mut chars = content.chars().peekable();
+
+    while let Some(char) = chars.next() {
+        if char != '\\' {
+            text.push(char);
+            continue;
+        }
+
+        match chars.next()? {
+            'n' => text.push('\n'),
+            'r' => text.push('\r'),
+            't' => text.push('\t'),
+            '\\' => text.push('\\'),
+            '0' => text.push('\0'),
+            '\'' => text.push('\''),
+            '"' => text.push('"'),
+            'x' => {
+                let high = chars.next()?.to_digit(8)?;
+                let low = chars.next()?.to_digit(16)?;
+                text.push(char::from_u32(high * 16 + low)?);
+            }
+            'u' => {
+                if chars.next()? != '{' {
+                    return None;
+                }
+                let mut code = 0;
+                loop {
+                    match chars.next()? {
+                        '}' => break,
+                        '_' => {}
+                        digit => code = code * 16 + digit.to_digit(16)?,
+                    }
+                }
+                text.push(char::from_u32(code)?);
+            }
+            // A line continuation, skipping the newline and the leading whitespace of the next
+            // line.
+            '\n' => while chars.next_if(|c| c.is_whitespace()).is_some() {},
+            _ => return None,
+        }
+    }
+
+    Some(text)
+}
+
+/// Encodes `text` into ISO8859-1 bytes, returning the first character that can't be encoded.
+fn encode(text: &str) -> Result<Vec<u8>, char> {
+    text.chars()
+        .map(|char| match char as u32 {
+            code @ (0x00..=0x7F | 0xA0..=0xFF) => Ok(code as u8),
+            _ => Err(char),
+        })
+        .collect()
+}
+
+/// Creates a `compile_error!` invocation with `message`, pointing at `span`.
+fn compile_error(message: &str, span: Span) -> TokenStream {
+    let mut message = Literal::string(message);
+    message.set_span(span);
+
+    let mut bang = Punct::new('!', Spacing::Alone);
+    bang.set_span(span);
+
+    let mut group = Group::new(Delimiter::Parenthesis, TokenTree::from(message).into());
+    group.set_span(span);
+
+    [
+        TokenTree::from(Ident::new("compile_error", span)),
+        TokenTree::from(bang),
+        TokenTree::from(group),
+    ]
+    .into_iter()
+    .collect()
+}
@@ -0,0 +1,118 @@
+//! [`bincode`] 2 encoding and decoding.
+//!
+//! The types are encoded as their ISO8859-1 bytes: a character as a single `u8`, and a string like
+//! a byte slice, its length followed by its bytes. Decoding validates the bytes.
+//!
+//! The `bincode` feature requires the Rust version of bincode 2, which is newer than the minimum
+//! supported Rust version of this crate.
+
+use ::bincode::{
+    de::{BorrowDecoder, Decoder},
+    enc::Encoder,
+    error::{DecodeError, EncodeError},
+    impl_borrow_decode, BorrowDecode, Decode, Encode,
+};
+
+use crate::{IsoLatin1Char, IsoLatin1Str, IsoLatin1String};
+
+impl Encode for IsoLatin1Char {
+    #[inline]
+    fn encode<E: Encoder>(&self, encoder: &mut E) -> Result<(), EncodeError> {
+        self.0.encode(encoder)
+    }
+}
+
+impl<Context> Decode<Context> for IsoLatin1Char {
+    fn decode<D: Decoder<Context = Context>>(decoder: &mut D) -> Result<Self, DecodeError> {
+        let byte = u8::decode(decoder)?;
+        IsoLatin1Char::try_from(byte).map_err(|err| DecodeError::OtherString(err.to_string()))
+    }
+}
+
+impl_borrow_decode!(IsoLatin1Char);
+
+impl Encode for IsoLatin1Str {
+    #[inline]
+    fn encode<E: Encoder>(&self, encoder: &mut E) -> Result<(), EncodeError> {
+        self.as_bytes().encode(encoder)
+    }
+}
+
+impl<'de, Context> BorrowDecode<'de, Context> for &'de IsoLatin1Str {
+    fn borrow_decode<D: BorrowDecoder<'de, Context = Context>>(
+        decoder: &mut D,
+    ) -> Result<Self, DecodeError> {
+        let bytes = <&[u8]>::borrow_decode(decoder)?;
+        IsoLatin1Str::from_iso8859_1(bytes).map_err(|err| DecodeError::OtherString(err.to_string()))
+    }
+}
+
+impl Encode for IsoLatin1String {
+    #[inline]
+    fn encode<E: Encoder>(&self, encoder: &mut E) -> Result<(), EncodeError> {
+        self.as_str().encode(encoder)
+    }
+}
+
+impl<Context> Decode<Context> for IsoLatin1String {
+    fn decode<D: Decoder<Context = Context>>(decoder: &mut D) -> Result<Self, DecodeError> {
+        let bytes = Vec::<u8>::decode(decoder)?;
+        IsoLatin1String::from_iso8859_1(bytes)
+            .map_err(|err| DecodeError::OtherString(err.to_string()))
+    }
+}
+
+impl_borrow_decode!(IsoLatin1String);
+
+#[cfg(test)]
+mod tests {
+    use ::bincode::config;
+
+    use super::*;
+
+    #[test]
+    fn char_round_trip() {
+        let char = IsoLatin1Char::try_from('ß').unwrap();
+        let bytes = ::bincode::encode_to_vec(char, config::standard()).unwrap();
+        assert_eq!(bytes, [0xDF]);
+
+        let (decoded, _): (IsoLatin1Char, _) =
+            ::bincode::decode_from_slice(&bytes, config::standard()).unwrap();
+        assert_eq!(decoded, char);
+
+        let result: Result<(IsoLatin1Char, _), _> =
+            ::bincode::decode_from_slice(&[0x85], config::standard());
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn string_round_trip() {
+        let s = IsoLatin1String::from_iso8859_1(b"Gr\xFC\xDFe".to_vec()).unwrap();
+        let bytes = ::bincode::encode_to_vec(&s, config::standard()).unwrap();
+        assert_eq!(bytes, b"\x05Gr\xFC\xDFe");
+        assert_eq!(
+            ::bincode::encode_to_vec(s.as_str(), config::standard()).unwrap(),
+            bytes
+        );
+
+        let (decoded, _): (IsoLatin1String, _) =
+            ::bincode::decode_from_slice(&bytes, config::standard()).unwrap();
+        assert_eq!(decoded, s);
+
+        let (borrowed, _): (&IsoLatin1Str, _) =
+            ::bincode::borrow_decode_from_slice(&bytes, config::standard()).unwrap();
+        assert_eq!(borrowed, s.as_str());
+    }
+
+    #[test]
+    fn decode_validates() {
+        let bytes = b"\x02a\x85";
+        let result: Result<(IsoLatin1String, _), _> =
+            ::bincode::decode_from_slice(bytes, config::standard());
+        assert!(result.is_err());
+
+        let result: Result<(&IsoLatin1Str, _), _> =
+            ::bincode::borrow_decode_from_slice(bytes, config::standard());
+        assert!(result.is_err());
+    }
+}
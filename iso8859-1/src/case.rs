@@ -0,0 +1,341 @@
+//! Case conversion support types.
+//!
+//! Every uppercase character of ISO8859-1 has its lowercase form in ISO8859-1, but three lowercase
+//! characters do not have a single uppercase form in it:
+//!
+//! - `ß` (`0xDF`), whose uppercase form is the two characters `SS`;
+//! - `ÿ` (`0xFF`), whose uppercase form is `Ÿ` (U+0178);
+//! - `µ` (`0xB5`), whose uppercase form is the greek `Μ` (U+039C).
+//!
+//! What happens to those characters when converting to uppercase is chosen with a
+//! [`UppercasePolicy`].
+
+use std::{
+    error::Error,
+    fmt::{self, Write},
+    iter::FusedIterator,
+};
+
+use crate::{IsoLatin1Char, IsoLatin1Str};
+
+/// What to do with characters whose uppercase form is not a single ISO8859-1 character.
+///
+/// Those characters are `ß`, `ÿ` and `µ`, see [`IsoLatin1Char::to_uppercase`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum UppercasePolicy {
+    /// Leave the character as it is.
+    #[default]
+    Keep,
+    /// Replace the character with the closest ISO8859-1 representation of its uppercase form:
+    /// `ß` becomes `SS` and `ÿ` becomes `Y`. `µ` is kept as it is, since `M` would change its
+    /// meaning (`5 µg` is not `5 MG`).
+    Substitute,
+    /// Fail the conversion with a [`UppercaseError`].
+    Error,
+}
+
+/// An iterator over the uppercase form of a [`IsoLatin1Char`].
+///
+/// This struct is created by the [`to_uppercase`] method on [`IsoLatin1Char`]. See its
+/// documentation for more.
+///
+/// [`to_uppercase`]: IsoLatin1Char::to_uppercase
+#[derive(Debug, Clone)]
+pub struct ToUppercase {
+    chars: [IsoLatin1Char; 2],
+    front: u8,
+    back: u8,
+}
+
+impl ToUppercase {
+    pub(crate) fn one(char: IsoLatin1Char) -> Self {
+        ToUppercase {
+            chars: [char, IsoLatin1Char(0)],
+            front: 0,
+            back: 1,
+        }
+    }
+
+    pub(crate) fn two(first: IsoLatin1Char, second: IsoLatin1Char) -> Self {
+        ToUppercase {
+            chars: [first, second],
+            front: 0,
+            back: 2,
+        }
+    }
+}
+
+impl Iterator for ToUppercase {
+    type Item = IsoLatin1Char;
+
+    #[inline]
+    fn next(&mut self) -> Option<IsoLatin1Char> {
+        if self.front == self.back {
+            return None;
+        }
+
+        let char = self.chars[usize::from(self.front)];
+        self.front += 1;
+        Some(char)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = usize::from(self.back - self.front);
+        (len, Some(len))
+    }
+}
+
+impl DoubleEndedIterator for ToUppercase {
+    #[inline]
+    fn next_back(&mut self) -> Option<IsoLatin1Char> {
+        if self.front == self.back {
+            return None;
+        }
+
+        self.back -= 1;
+        Some(self.chars[usize::from(self.back)])
+    }
+}
+
+impl ExactSizeIterator for ToUppercase {}
+
+impl FusedIterator for ToUppercase {}
+
+/// The error returned when converting to uppercase with [`UppercasePolicy::Error`] and a character
+/// has no single ISO8859-1 uppercase form.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct UppercaseError {
+    char: IsoLatin1Char,
+    index: usize,
+}
+
+impl UppercaseError {
+    pub(crate) fn new(char: IsoLatin1Char, index: usize) -> Self {
+        UppercaseError { char, index }
+    }
+
+    /// Returns the character that could not be converted.
+    pub fn char(&self) -> IsoLatin1Char {
+        self.char
+    }
+
+    /// Returns the index of the character in the converted string.
+    ///
+    /// Always `0` when converting a single [`IsoLatin1Char`].
+    pub fn index(&self) -> usize {
+        self.index
+    }
+}
+
+impl fmt::Display for UppercaseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:?} at index {} has no uppercase form in ISO8859-1",
+            char::from(self.char),
+            self.index
+        )
+    }
+}
+
+impl Error for UppercaseError {}
+
+/// Displays a string slice in lowercase.
+///
+/// This struct is created by the [`display_lowercase`] method on [`IsoLatin1Str`]. See its
+/// documentation for more.
+///
+/// [`display_lowercase`]: IsoLatin1Str::display_lowercase
+#[derive(Debug, Clone, Copy)]
+pub struct DisplayLowercase<'a> {
+    s: &'a IsoLatin1Str,
+}
+
+impl<'a> DisplayLowercase<'a> {
+    pub(crate) fn new(s: &'a IsoLatin1Str) -> Self {
+        DisplayLowercase { s }
+    }
+}
+
+impl fmt::Display for DisplayLowercase<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for char in self.s.chars() {
+            f.write_char(char.to_lowercase().into())?;
+        }
+        Ok(())
+    }
+}
+
+/// Displays a string slice in uppercase.
+///
+/// This struct is created by the [`display_uppercase`] method on [`IsoLatin1Str`]. See its
+/// documentation for more.
+///
+/// [`display_uppercase`]: IsoLatin1Str::display_uppercase
+#[derive(Debug, Clone, Copy)]
+pub struct DisplayUppercase<'a> {
+    s: &'a IsoLatin1Str,
+}
+
+impl<'a> DisplayUppercase<'a> {
+    pub(crate) fn new(s: &'a IsoLatin1Str) -> Self {
+        DisplayUppercase { s }
+    }
+}
+
+impl fmt::Display for DisplayUppercase<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for char in self.s.chars() {
+            for upper in char::from(char).to_uppercase() {
+                f.write_char(upper)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::IsoLatin1Str;
+
+    fn latin1(bytes: &[u8]) -> &IsoLatin1Str {
+        IsoLatin1Str::from_iso8859_1(bytes).unwrap()
+    }
+
+    #[test]
+    fn char_to_lowercase() {
+        for byte in (0x00..=0x7F).chain(0xA0..=0xFF) {
+            let char = IsoLatin1Char(byte);
+            let expected: Vec<char> = char::from(char).to_lowercase().collect();
+            assert_eq!(
+                vec![char::from(char.to_lowercase())],
+                expected,
+                "0x{byte:x}"
+            );
+        }
+    }
+
+    #[test]
+    fn char_to_uppercase() {
+        // Everything with a single ISO8859-1 uppercase form agrees with `char`.
+        for byte in (0x00..=0x7F).chain(0xA0..=0xFF) {
+            if matches!(byte, 0xB5 | 0xDF | 0xFF) {
+                continue;
+            }
+            let char = IsoLatin1Char(byte);
+            let upper: Vec<char> = char
+                .to_uppercase(UppercasePolicy::Error)
+                .unwrap()
+                .map(char::from)
+                .collect();
+            let expected: Vec<char> = char::from(char).to_uppercase().collect();
+            assert_eq!(upper, expected, "0x{byte:x}");
+        }
+    }
+
+    #[test]
+    fn char_to_uppercase_policy() {
+        let sharp_s = IsoLatin1Char(0xDF);
+        let y_diaeresis = IsoLatin1Char(0xFF);
+        let micro = IsoLatin1Char(0xB5);
+
+        for char in [sharp_s, y_diaeresis, micro] {
+            let kept: Vec<_> = char.to_uppercase(UppercasePolicy::Keep).unwrap().collect();
+            assert_eq!(kept, [char]);
+
+            let err = char.to_uppercase(UppercasePolicy::Error).unwrap_err();
+            assert_eq!(err.char(), char);
+            assert_eq!(err.index(), 0);
+        }
+
+        let substitute = |char: IsoLatin1Char| -> Vec<u8> {
+            char.to_uppercase(UppercasePolicy::Substitute)
+                .unwrap()
+                .map(u8::from)
+                .collect()
+        };
+        assert_eq!(substitute(sharp_s), b"SS");
+        assert_eq!(substitute(y_diaeresis), b"Y");
+        assert_eq!(substitute(micro), b"\xB5");
+    }
+
+    #[test]
+    fn str_to_lowercase() {
+        let s = latin1(b"\xC6GIR \xD8L \xD7 \xDF");
+        assert_eq!(s.to_lowercase().as_bytes(), b"\xE6gir \xF8l \xD7 \xDF");
+    }
+
+    #[test]
+    fn str_to_uppercase() {
+        let s = latin1(b"stra\xDFe \xE6\xF8\xE5 \xFF \xF7");
+
+        let upper = s.to_uppercase(UppercasePolicy::Keep).unwrap();
+        assert_eq!(upper.as_bytes(), b"STRA\xDFE \xC6\xD8\xC5 \xFF \xF7");
+
+        let upper = s.to_uppercase(UppercasePolicy::Substitute).unwrap();
+        assert_eq!(upper.as_bytes(), b"STRASSE \xC6\xD8\xC5 Y \xF7");
+
+        let upper = latin1(b"5 \xB5g").to_uppercase(UppercasePolicy::Substitute);
+        assert_eq!(upper.unwrap().as_bytes(), b"5 \xB5G");
+
+        let err = s.to_uppercase(UppercasePolicy::Error).unwrap_err();
+        assert_eq!(err.char(), IsoLatin1Char(0xDF));
+        assert_eq!(err.index(), 4);
+    }
+
+    #[test]
+    fn display_case() {
+        let s = latin1(b"\xC6gir \xF8L \xDF\xFF\xB5 \xD7\xF7");
+
+        assert_eq!(
+            s.display_lowercase().to_string(),
+            s.to_lowercase().to_string()
+        );
+        assert_eq!(s.display_lowercase().to_string(), "ægir øl ßÿµ ×÷");
+        assert_eq!(s.display_uppercase().to_string(), "ÆGIR ØL SSŸΜ ×÷");
+        assert_eq!(
+            s.display_uppercase().to_string(),
+            s.to_string().to_uppercase()
+        );
+    }
+
+    #[test]
+    fn str_to_titlecase() {
+        let s = latin1(b"  \xC6GIR  o'neil \xDFtra\xDFe 2nd-place");
+
+        let title = s.to_titlecase(UppercasePolicy::Keep).unwrap();
+        assert_eq!(
+            title.as_bytes(),
+            b"  \xC6gir  O'neil \xDFtra\xDFe 2nd-Place"
+        );
+
+        let title = s.to_titlecase(UppercasePolicy::Substitute).unwrap();
+        assert_eq!(title.as_bytes(), b"  \xC6gir  O'neil Sstra\xDFe 2nd-Place");
+
+        let err = s.to_titlecase(UppercasePolicy::Error).unwrap_err();
+        assert_eq!(err.index(), 15);
+
+        assert!(latin1(b"")
+            .to_titlecase(UppercasePolicy::Error)
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn str_capitalize_first() {
+        let s = latin1(b"\xFFoung ONES");
+        let capitalized = s.capitalize_first(UppercasePolicy::Substitute).unwrap();
+        assert_eq!(capitalized.as_bytes(), b"Young ONES");
+
+        let capitalized = s.capitalize_first(UppercasePolicy::Keep).unwrap();
+        assert_eq!(capitalized.as_bytes(), b"\xFFoung ONES");
+
+        let err = s.capitalize_first(UppercasePolicy::Error).unwrap_err();
+        assert_eq!(err.index(), 0);
+
+        let capitalized = latin1(b"\xDF!").capitalize_first(UppercasePolicy::Substitute);
+        assert_eq!(capitalized.unwrap().as_bytes(), b"Ss!");
+    }
+}
@@ -0,0 +1,147 @@
+//! Comparisons between the ISO8859-1 types and the standard library string types.
+//!
+//! Values are compared by their decoded characters, so a `IsoLatin1String` holding `Grüße` is
+//! equal to the `"Grüße"` literal even though their bytes differ.
+
+use crate::{IsoLatin1Char, IsoLatin1Str, IsoLatin1String};
+
+impl PartialEq<char> for IsoLatin1Char {
+    #[inline]
+    fn eq(&self, other: &char) -> bool {
+        char::from(*self) == *other
+    }
+}
+
+impl PartialEq<IsoLatin1Char> for char {
+    #[inline]
+    fn eq(&self, other: &IsoLatin1Char) -> bool {
+        *self == char::from(*other)
+    }
+}
+
+impl PartialEq<str> for IsoLatin1Str {
+    fn eq(&self, other: &str) -> bool {
+        // Every character takes at least one byte in UTF-8.
+        if other.len() < self.len() {
+            return false;
+        }
+
+        self.chars().map(char::from).eq(other.chars())
+    }
+}
+
+impl PartialEq<IsoLatin1Str> for str {
+    #[inline]
+    fn eq(&self, other: &IsoLatin1Str) -> bool {
+        other == self
+    }
+}
+
+impl PartialEq<IsoLatin1String> for IsoLatin1Str {
+    #[inline]
+    fn eq(&self, other: &IsoLatin1String) -> bool {
+        self == other.as_str()
+    }
+}
+
+impl PartialEq<IsoLatin1Str> for IsoLatin1String {
+    #[inline]
+    fn eq(&self, other: &IsoLatin1Str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&IsoLatin1Str> for IsoLatin1String {
+    #[inline]
+    fn eq(&self, other: &&IsoLatin1Str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl PartialEq<IsoLatin1String> for &IsoLatin1Str {
+    #[inline]
+    fn eq(&self, other: &IsoLatin1String) -> bool {
+        *self == other.as_str()
+    }
+}
+
+/// Implements `PartialEq` in both directions between a ISO8859-1 type and a std string type,
+/// comparing them as `IsoLatin1Str` and `str`.
+macro_rules! impl_eq {
+    ($lhs:ty, $rhs:ty) => {
+        #[allow(unused_lifetimes)]
+        impl<'a> PartialEq<$rhs> for $lhs {
+            #[inline]
+            fn eq(&self, other: &$rhs) -> bool {
+                let (lhs, rhs): (&IsoLatin1Str, &str) = (self.as_ref(), other.as_ref());
+                lhs == rhs
+            }
+        }
+
+        #[allow(unused_lifetimes)]
+        impl<'a> PartialEq<$lhs> for $rhs {
+            #[inline]
+            fn eq(&self, other: &$lhs) -> bool {
+                let (lhs, rhs): (&IsoLatin1Str, &str) = (other.as_ref(), self.as_ref());
+                lhs == rhs
+            }
+        }
+    };
+}
+
+impl_eq! { IsoLatin1Str, String }
+impl_eq! { &'a IsoLatin1Str, String }
+impl_eq! { IsoLatin1Str, &'a str }
+impl_eq! { IsoLatin1String, str }
+impl_eq! { IsoLatin1String, &'a str }
+impl_eq! { IsoLatin1String, String }
+
+#[cfg(test)]
+mod tests {
+    use crate::{IsoLatin1Char, IsoLatin1Str, IsoLatin1String};
+
+    #[test]
+    fn char_eq_char() {
+        let ash = IsoLatin1Char::try_from('Æ').unwrap();
+        assert_eq!(ash, 'Æ');
+        assert_eq!('Æ', ash);
+        assert_ne!(ash, 'æ');
+        assert_ne!(ash, 'Ǽ');
+    }
+
+    #[test]
+    fn str_eq_str() {
+        let s = IsoLatin1Str::from_iso8859_1(b"Gr\xFC\xDFe").unwrap();
+        assert_eq!(s, "Grüße");
+        assert_eq!("Grüße", s);
+        assert_eq!(*s, *"Grüße");
+        assert_eq!(s, String::from("Grüße"));
+        assert_eq!(String::from("Grüße"), s);
+
+        assert_ne!(s, "Gruße");
+        assert_ne!(s, "Grüßen");
+        assert_ne!(s, "Grü");
+        assert_ne!(s, "");
+    }
+
+    #[test]
+    fn string_eq_str() {
+        let s = IsoLatin1String::from_iso8859_1(b"Gr\xFC\xDFe".to_vec()).unwrap();
+        assert_eq!(s, "Grüße");
+        assert_eq!("Grüße", s);
+        assert_eq!(s, *"Grüße");
+        assert_eq!(s, String::from("Grüße"));
+        assert_eq!(String::from("Grüße"), s);
+        assert_ne!(s, "Grüsse");
+    }
+
+    #[test]
+    fn string_eq_latin1_str() {
+        let s = IsoLatin1String::from_iso8859_1(b"Gr\xFC\xDFe".to_vec()).unwrap();
+        let slice = IsoLatin1Str::from_iso8859_1(b"Gr\xFC\xDFe").unwrap();
+        assert_eq!(s, slice);
+        assert_eq!(slice, s);
+        assert_eq!(s, *slice);
+        assert_eq!(*slice, s);
+    }
+}
@@ -0,0 +1,176 @@
+//! Composition of decomposed text into ISO8859-1 characters.
+//!
+//! Decomposed text, as produced by some file systems and input methods, writes letters with
+//! diacritics as a base letter followed by a combining mark, like `A` followed by `U+030A COMBINING
+//! RING ABOVE` for `Å`. Only the precomposed form is in ISO8859-1, so encoders compose those
+//! sequences first.
+//!
+//! This covers the part of Unicode normalization form C (NFC) whose result is in ISO8859-1: the
+//! canonical decompositions of the ISO8859-1 letters, and the few characters that are canonically
+//! equivalent to a ISO8859-1 character, like `U+212B ANGSTROM SIGN`.
+
+use std::{iter::Peekable, str::CharIndices};
+
+/// The ISO8859-1 characters with a canonical decomposition, as base, combining mark and
+/// precomposed character.
+const PAIRS: [(char, char, char); 53] = [
+    ('A', '\u{300}', 'À'),
+    ('A', '\u{301}', 'Á'),
+    ('A', '\u{302}', 'Â'),
+    ('A', '\u{303}', 'Ã'),
+    ('A', '\u{308}', 'Ä'),
+    ('A', '\u{30A}', 'Å'),
+    ('C', '\u{327}', 'Ç'),
+    ('E', '\u{300}', 'È'),
+    ('E', '\u{301}', 'É'),
+    ('E', '\u{302}', 'Ê'),
+    ('E', '\u{308}', 'Ë'),
+    ('I', '\u{300}', 'Ì'),
+    ('I', '\u{301}', 'Í'),
+    ('I', '\u{302}', 'Î'),
+    ('I', '\u{308}', 'Ï'),
+    ('N', '\u{303}', 'Ñ'),
+    ('O', '\u{300}', 'Ò'),
+    ('O', '\u{301}', 'Ó'),
+    ('O', '\u{302}', 'Ô'),
+    ('O', '\u{303}', 'Õ'),
+    ('O', '\u{308}', 'Ö'),
+    ('U', '\u{300}', 'Ù'),
+    ('U', '\u{301}', 'Ú'),
+    ('U', '\u{302}', 'Û'),
+    ('U', '\u{308}', 'Ü'),
+    ('Y', '\u{301}', 'Ý'),
+    ('a', '\u{300}', 'à'),
+    ('a', '\u{301}', 'á'),
+    ('a', '\u{302}', 'â'),
+    ('a', '\u{303}', 'ã'),
+    ('a', '\u{308}', 'ä'),
+    ('a', '\u{30A}', 'å'),
+    ('c', '\u{327}', 'ç'),
+    ('e', '\u{300}', 'è'),
+    ('e', '\u{301}', 'é'),
+    ('e', '\u{302}', 'ê'),
+    ('e', '\u{308}', 'ë'),
+    ('i', '\u{300}', 'ì'),
+    ('i', '\u{301}', 'í'),
+    ('i', '\u{302}', 'î'),
+    ('i', '\u{308}', 'ï'),
+    ('n', '\u{303}', 'ñ'),
+    ('o', '\u{300}', 'ò'),
+    ('o', '\u{301}', 'ó'),
+    ('o', '\u{302}', 'ô'),
+    ('o', '\u{303}', 'õ'),
+    ('o', '\u{308}', 'ö'),
+    ('u', '\u{300}', 'ù'),
+    ('u', '\u{301}', 'ú'),
+    ('u', '\u{302}', 'û'),
+    ('u', '\u{308}', 'ü'),
+    ('y', '\u{301}', 'ý'),
+    ('y', '\u{308}', 'ÿ'),
+];
+
+/// The characters outside of ISO8859-1 that are canonically equivalent to a ISO8859-1 character.
+const SINGLETONS: [(char, char); 6] = [
+    ('\u{37E}', ';'),
+    ('\u{387}', '·'),
+    ('\u{1FEF}', '`'),
+    ('\u{1FFD}', '´'),
+    ('\u{212A}', 'K'),
+    ('\u{212B}', 'Å'),
+];
+
+/// Returns the ISO8859-1 character `base` followed by `mark` composes to, if any.
+fn compose_pair(base: char, mark: char) -> Option<char> {
+    PAIRS
+        .iter()
+        .find(|&&(b, m, _)| b == base && m == mark)
+        .map(|&(_, _, composed)| composed)
+}
+
+/// Returns the ISO8859-1 character `char` is canonically equivalent to, or `char` itself.
+fn compose_singleton(char: char) -> char {
+    SINGLETONS
+        .iter()
+        .find(|&&(singleton, _)| singleton == char)
+        .map_or(char, |&(_, composed)| composed)
+}
+
+/// An iterator over the characters of a string slice and their byte index, where the sequences
+/// that compose to a ISO8859-1 character are replaced by it, with the index of their first
+/// character.
+#[derive(Debug, Clone)]
+pub(crate) struct Composed<'a> {
+    chars: Peekable<CharIndices<'a>>,
+}
+
+impl<'a> Composed<'a> {
+    pub(crate) fn new(s: &'a str) -> Self {
+        Composed {
+            chars: s.char_indices().peekable(),
+        }
+    }
+}
+
+impl Iterator for Composed<'_> {
+    type Item = (usize, char);
+
+    fn next(&mut self) -> Option<(usize, char)> {
+        let (index, char) = self.chars.next()?;
+        let char = compose_singleton(char);
+
+        let composed = self
+            .chars
+            .peek()
+            .and_then(|&(_, mark)| compose_pair(char, mark));
+        match composed {
+            Some(composed) => {
+                self.chars.next();
+                Some((index, composed))
+            }
+            None => Some((index, char)),
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (len, max) = self.chars.size_hint();
+        ((len + 1) / 2, max)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn composed(s: &str) -> Vec<(usize, char)> {
+        Composed::new(s).collect()
+    }
+
+    #[test]
+    fn pairs() {
+        for &(base, mark, precomposed) in &PAIRS {
+            assert!(u32::from(precomposed) <= 0xFF, "{precomposed:?}");
+            let decomposed: String = [base, mark].iter().collect();
+            assert_eq!(composed(&decomposed), [(0, precomposed)]);
+        }
+    }
+
+    #[test]
+    fn sequences() {
+        assert_eq!(
+            composed("Jose\u{301} u\u{308}"),
+            [(0, 'J'), (1, 'o'), (2, 's'), (3, 'é'), (6, ' '), (7, 'ü')]
+        );
+        // Marks that don't compose into ISO8859-1 are kept.
+        assert_eq!(composed("o\u{30B}"), [(0, 'o'), (1, '\u{30B}')]);
+        assert_eq!(composed("\u{301}e"), [(0, '\u{301}'), (2, 'e')]);
+        assert_eq!(composed("a\u{308}\u{301}"), [(0, 'ä'), (3, '\u{301}')]);
+    }
+
+    #[test]
+    fn singletons() {
+        assert_eq!(composed("\u{212B}"), [(0, 'Å')]);
+        assert_eq!(composed("5\u{212A}"), [(0, '5'), (1, 'K')]);
+        assert_eq!(composed("\u{212A}\u{301}"), [(0, 'K'), (3, '\u{301}')]);
+    }
+}
@@ -0,0 +1,122 @@
+//! Decoding of mislabeled ISO8859-1 data with a Windows-1252 fallback.
+//!
+//! A lot of text labeled as ISO8859-1 is really Windows-1252, which assigns printable characters
+//! (smart quotes, the euro sign, dashes, ...) to the `0x80` to `0x9F` range that ISO8859-1 leaves
+//! undefined. Browsers and mail clients handle this by decoding those bytes as Windows-1252, and
+//! this module does the same.
+//!
+//! Since those characters can not be represented in ISO8859-1, the functions of this module
+//! decode into a [`String`].
+//!
+//! # Examples
+//!
+//! Basic usage:
+//!
+//! ```
+//! use iso8859_1::cp1252;
+//!
+//! let (text, fallbacks) = cp1252::decode_with_fallbacks(b"\x93caf\xE9\x94 \x80 5");
+//!
+//! assert_eq!(text, "“café” € 5");
+//! assert_eq!(fallbacks, [0, 5, 7]);
+//! ```
+
+/// Windows-1252 assignments for the `0x80` to `0x9F` range.
+///
+/// The five bytes Windows-1252 leaves undefined (`0x81`, `0x8D`, `0x8F`, `0x90` and `0x9D`) are
+/// mapped to the C1 control code with the same value, which is what the WHATWG Encoding Standard
+/// does. This keeps decoding infallible and lets those bytes round-trip.
+static WINDOWS_1252_HIGH: [char; 32] = [
+    '€', '\u{81}', '‚', 'ƒ', '„', '…', '†', '‡', 'ˆ', '‰', 'Š', '‹', 'Œ', '\u{8D}', 'Ž', '\u{8F}',
+    '\u{90}', '‘', '’', '“', '”', '•', '–', '—', '˜', '™', 'š', '›', 'œ', '\u{9D}', 'ž', 'Ÿ',
+];
+
+/// Decodes a single byte, using the Windows-1252 assignment if it is undefined in ISO8859-1.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use iso8859_1::cp1252;
+///
+/// assert_eq!(cp1252::decode_byte(0xE9), 'é');
+/// assert_eq!(cp1252::decode_byte(0x80), '€');
+/// ```
+pub fn decode_byte(byte: u8) -> char {
+    match byte {
+        0x80..=0x9F => WINDOWS_1252_HIGH[usize::from(byte - 0x80)],
+        _ => char::from(byte),
+    }
+}
+
+/// Decodes `bytes` as ISO8859-1, using the Windows-1252 assignments for bytes undefined in
+/// ISO8859-1.
+///
+/// This never fails: every byte maps to some character.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use iso8859_1::cp1252;
+///
+/// assert_eq!(cp1252::decode(b"na\xEFve \x96 \x85"), "naïve – …");
+/// ```
+pub fn decode(bytes: &[u8]) -> String {
+    let mut text = String::with_capacity(bytes.len());
+    text.extend(bytes.iter().map(|&byte| decode_byte(byte)));
+    text
+}
+
+/// Same as [`decode`], but also returns the positions of the bytes that were decoded using the
+/// Windows-1252 fallback.
+///
+/// An empty list of positions means `bytes` was valid ISO8859-1.
+pub fn decode_with_fallbacks(bytes: &[u8]) -> (String, Vec<usize>) {
+    let fallbacks = bytes
+        .iter()
+        .enumerate()
+        .filter(|(_, byte)| matches!(byte, 0x80..=0x9F))
+        .map(|(index, _)| index)
+        .collect();
+
+    (decode(bytes), fallbacks)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_byte() {
+        for byte in (0x00..=0x7F).chain(0xA0..=0xFF) {
+            assert_eq!(super::decode_byte(byte), char::from(byte), "0x{byte:x}");
+        }
+
+        assert_eq!(super::decode_byte(0x80), '€');
+        assert_eq!(super::decode_byte(0x8A), 'Š');
+        assert_eq!(super::decode_byte(0x91), '‘');
+        assert_eq!(super::decode_byte(0x9F), 'Ÿ');
+
+        for byte in [0x81, 0x8D, 0x8F, 0x90, 0x9D] {
+            assert_eq!(super::decode_byte(byte), char::from(byte), "0x{byte:x}");
+        }
+    }
+
+    #[test]
+    fn decode_valid() {
+        let (text, fallbacks) = decode_with_fallbacks(b"Gr\xFC\xDFe");
+        assert_eq!(text, "Grüße");
+        assert!(fallbacks.is_empty());
+    }
+
+    #[test]
+    fn decode_mislabeled() {
+        let (text, fallbacks) = decode_with_fallbacks(b"\x84Gr\xFC\xDFe\x93 \x97 \x99");
+        assert_eq!(text, "„Grüße“ — ™");
+        assert_eq!(fallbacks, [0, 6, 8, 10]);
+        assert_eq!(decode(b"\x84Gr\xFC\xDFe\x93 \x97 \x99"), text);
+    }
+}
@@ -0,0 +1,179 @@
+//! Integration with the [`csv`] crate.
+//!
+//! Reading CSV with [`csv::Reader::read_byte_record`](::csv::Reader::read_byte_record) does not
+//! assume any encoding, so it works on ISO8859-1 files as they are. The [`fields`] function then
+//! validates the fields of a [`ByteRecord`] as [`IsoLatin1Str`] without copying them.
+//!
+//! Writing needs no helper: the string types implement `AsRef<[u8]>`, so they can be passed to
+//! [`csv::Writer::write_record`](::csv::Writer::write_record) directly.
+//!
+//! This module is only available with the `csv` feature.
+//!
+//! # Examples
+//!
+//! Basic usage:
+//!
+//! ```
+//! use csv::{ByteRecord, ReaderBuilder};
+//! use iso8859_1::csv::fields;
+//!
+//! let data = b"nome;cidade\nJo\xE3o;S\xE3o Paulo\n";
+//! let mut reader = ReaderBuilder::new().delimiter(b';').from_reader(&data[..]);
+//!
+//! let mut record = ByteRecord::new();
+//! while reader.read_byte_record(&mut record).unwrap() {
+//!     let fields = fields(&record).collect::<Result<Vec<_>, _>>().unwrap();
+//!     assert_eq!(fields, ["João", "São Paulo"]);
+//! }
+//! ```
+
+use std::{error::Error, fmt};
+
+use ::csv::{ByteRecord, ByteRecordIter, Position};
+
+use crate::{Iso8859_1Error, IsoLatin1Str};
+
+/// Returns an iterator over the fields of `record`, validated as ISO8859-1.
+pub fn fields(record: &ByteRecord) -> Fields<'_> {
+    Fields {
+        record,
+        iter: record.iter().enumerate(),
+    }
+}
+
+/// An iterator over the fields of a [`ByteRecord`] as [`IsoLatin1Str`].
+///
+/// This struct is created by the [`fields`] function. See its documentation for more.
+#[derive(Clone)]
+pub struct Fields<'a> {
+    record: &'a ByteRecord,
+    iter: std::iter::Enumerate<ByteRecordIter<'a>>,
+}
+
+impl fmt::Debug for Fields<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Fields")
+            .field("record", &self.record)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'a> Iterator for Fields<'a> {
+    type Item = Result<&'a IsoLatin1Str, FieldError>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let (index, field) = self.iter.next()?;
+        Some(
+            IsoLatin1Str::from_iso8859_1(field).map_err(|error| FieldError {
+                field: index,
+                position: self.record.position().cloned(),
+                error,
+            }),
+        )
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl ExactSizeIterator for Fields<'_> {}
+
+/// The error returned when a field of a CSV record is not valid ISO8859-1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldError {
+    field: usize,
+    position: Option<Position>,
+    error: Iso8859_1Error,
+}
+
+impl FieldError {
+    /// Returns the index of the invalid field in its record.
+    pub fn field(&self) -> usize {
+        self.field
+    }
+
+    /// Returns the position of the record in the CSV data, if the record has one.
+    ///
+    /// Records read by a [`csv::Reader`] always have a position.
+    pub fn position(&self) -> Option<&Position> {
+        self.position.as_ref()
+    }
+
+    /// Returns the error that made the field invalid, with the index of the invalid byte in the
+    /// field.
+    pub fn iso8859_1_error(&self) -> Iso8859_1Error {
+        self.error
+    }
+}
+
+impl fmt::Display for FieldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.position {
+            Some(position) => write!(
+                f,
+                "field {} of record {} (line {}): {}",
+                self.field,
+                position.record(),
+                position.line(),
+                self.error
+            ),
+            None => write!(f, "field {}: {}", self.field, self.error),
+        }
+    }
+}
+
+impl Error for FieldError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ::csv::{ReaderBuilder, WriterBuilder};
+
+    use super::*;
+    use crate::IsoLatin1String;
+
+    #[test]
+    fn read_fields() {
+        let data = b"a;b\n\xC6gir;\xF8l\nok;\x85\n";
+        let mut reader = ReaderBuilder::new().delimiter(b';').from_reader(&data[..]);
+        let mut record = ByteRecord::new();
+
+        assert!(reader.read_byte_record(&mut record).unwrap());
+        let row: Vec<&IsoLatin1Str> = fields(&record).collect::<Result<_, _>>().unwrap();
+        assert_eq!(row, ["Ægir", "øl"]);
+
+        assert!(reader.read_byte_record(&mut record).unwrap());
+        let mut iter = fields(&record);
+        assert_eq!(iter.len(), 2);
+        assert_eq!(iter.next().unwrap().unwrap(), "ok");
+
+        let err = iter.next().unwrap().unwrap_err();
+        assert_eq!(err.field(), 1);
+        assert_eq!(err.iso8859_1_error().valid_up_to(), 0);
+        assert_eq!(err.position().unwrap().line(), 3);
+        assert_eq!(
+            err.to_string(),
+            "field 1 of record 2 (line 3): character undefined in ISO8859-1: byte 0x85 at index 0"
+        );
+    }
+
+    #[test]
+    fn write_fields() {
+        let row = [
+            IsoLatin1String::from_iso8859_1(b"Jo\xE3o".to_vec()).unwrap(),
+            IsoLatin1String::from_iso8859_1(b"a;b".to_vec()).unwrap(),
+        ];
+
+        let mut writer = WriterBuilder::new().delimiter(b';').from_writer(vec![]);
+        writer.write_record(&row).unwrap();
+        let data = writer.into_inner().unwrap();
+
+        assert_eq!(data, b"Jo\xE3o;\"a;b\"\n");
+    }
+}
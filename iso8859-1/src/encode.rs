@@ -0,0 +1,147 @@
+//! Strict encoding of UTF-8 text.
+//!
+//! [`IsoLatin1String`] implements [`FromStr`] and `TryFrom<&str>`, which fail on the first
+//! character that is not in ISO8859-1 and report where it is. The lossy counterparts, which
+//! transliterate those characters instead, are [`IsoLatin1String::from_str_lossy`] and
+//! [`IsoLatin1String::from_str_with`].
+//!
+//! Decomposed sequences that are canonically equivalent to a ISO8859-1 character, like `A`
+//! followed by `U+030A COMBINING RING ABOVE`, are encoded as that character.
+
+use std::{error::Error, fmt, str::FromStr};
+
+use crate::{compose::Composed, IsoLatin1Char, IsoLatin1String};
+
+impl FromStr for IsoLatin1String {
+    type Err = FromStrError;
+
+    /// Encodes a UTF-8 string into a `IsoLatin1String`.
+    ///
+    /// A base letter followed by a combining mark is encoded as the precomposed ISO8859-1
+    /// character, when there is one.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] if a character is not in ISO8859-1, with its byte index in `s`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use iso8859_1::IsoLatin1String;
+    ///
+    /// let s: IsoLatin1String = "Grüße".parse().unwrap();
+    /// assert_eq!(s.as_bytes(), b"Gr\xFC\xDFe");
+    ///
+    /// let s: IsoLatin1String = "A\u{30A}ngstro\u{308}m".parse().unwrap();
+    /// assert_eq!(s.as_bytes(), b"\xC5ngstr\xF6m");
+    ///
+    /// let err = "5 €".parse::<IsoLatin1String>().unwrap_err();
+    /// assert_eq!(err.valid_up_to(), 2);
+    /// assert_eq!(err.char(), '€');
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut bytes = Vec::with_capacity(s.len());
+        for (index, char) in Composed::new(s) {
+            match IsoLatin1Char::try_from(char) {
+                Ok(char) => bytes.push(u8::from(char)),
+                Err(_) => {
+                    return Err(FromStrError {
+                        valid_up_to: index,
+                        char,
+                    })
+                }
+            }
+        }
+
+        // SAFETY: every byte comes from a `IsoLatin1Char`.
+        Ok(unsafe { IsoLatin1String::from_iso8859_1_unchecked(bytes) })
+    }
+}
+
+impl TryFrom<&str> for IsoLatin1String {
+    type Error = FromStrError;
+
+    #[inline]
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+/// A possible error value when encoding a UTF-8 string into a `IsoLatin1String`.
+///
+/// This type is the error type for the [`FromStr`] and `TryFrom<&str>` implementations of
+/// [`IsoLatin1String`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FromStrError {
+    valid_up_to: usize,
+    char: char,
+}
+
+impl FromStrError {
+    /// Returns the byte index in the given string up to which the characters could be encoded.
+    pub fn valid_up_to(&self) -> usize {
+        self.valid_up_to
+    }
+
+    /// Returns the character that could not be encoded.
+    pub fn char(&self) -> char {
+        self.char
+    }
+}
+
+impl fmt::Display for FromStrError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "character {:?} at index {} is not a ISO8859-1 character",
+            self.char, self.valid_up_to
+        )
+    }
+}
+
+impl Error for FromStrError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_str() {
+        let s: IsoLatin1String = "".parse().unwrap();
+        assert!(s.is_empty());
+
+        let s = IsoLatin1String::try_from("\u{0}~\u{A0}ÿ").unwrap();
+        assert_eq!(s.as_bytes(), b"\x00~\xA0\xFF");
+    }
+
+    #[test]
+    fn from_str_composed() {
+        let s = IsoLatin1String::try_from("A\u{30A} C\u{327}a\u{300} \u{212B}").unwrap();
+        assert_eq!(s, "Å Çà Å");
+
+        // The combining mark is reported when it doesn't compose into ISO8859-1.
+        let err = "Erdo\u{30B}s".parse::<IsoLatin1String>().unwrap_err();
+        assert_eq!(err.valid_up_to(), 4);
+        assert_eq!(err.char(), '\u{30B}');
+    }
+
+    #[test]
+    fn from_str_error() {
+        let err = IsoLatin1String::try_from("ab\u{85}").unwrap_err();
+        assert_eq!(err.valid_up_to(), 2);
+        assert_eq!(err.char(), '\u{85}');
+
+        let err = "Łódź".parse::<IsoLatin1String>().unwrap_err();
+        assert_eq!(err.valid_up_to(), 0);
+        assert_eq!(err.char(), 'Ł');
+
+        let err = "ó ✓".parse::<IsoLatin1String>().unwrap_err();
+        assert_eq!(err.valid_up_to(), 3);
+        assert_eq!(
+            err.to_string(),
+            "character '✓' at index 3 is not a ISO8859-1 character"
+        );
+    }
+}
@@ -0,0 +1,169 @@
+//! An object-safe abstraction over encoded strings.
+//!
+//! Applications handling several encodings at runtime can store their strings as
+//! `Box<dyn EncodedStr>` or `&dyn EncodedStr`, and still get at their bytes, their UTF-8 text and
+//! the name of their encoding. The `gsm0338` crate implements the trait for its GSM 03.38 strings
+//! too.
+//!
+//! As [`IsoLatin1Str`] is unsized, a borrowed slice is turned into a trait object through its
+//! reference: `&&IsoLatin1Str` coerces to `&dyn EncodedStr`, and `&IsoLatin1Str` to
+//! `Box<dyn EncodedStr + '_>`.
+
+use crate::{IsoLatin1Str, IsoLatin1String};
+
+/// A string in some encoding, usable as a trait object.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use iso8859_1::{EncodedStr, IsoLatin1Str, IsoLatin1String};
+///
+/// let s = IsoLatin1Str::from_iso8859_1(b"na\xEFve").unwrap();
+/// let strings: Vec<Box<dyn EncodedStr>> = vec![
+///     Box::new(s),
+///     Box::new(IsoLatin1String::from_str_lossy("cr\u{E8}me")),
+/// ];
+///
+/// for s in &strings {
+///     assert_eq!(s.encoding_name(), "ISO-8859-1");
+///     assert_eq!(s.len(), 5);
+/// }
+/// assert_eq!(strings[0].to_utf8(), "naïve");
+/// assert_eq!(strings[1].char_at(2), Some('è'));
+/// ```
+pub trait EncodedStr {
+    /// Returns the length of the string, in bytes.
+    ///
+    /// This is the length of [`as_bytes`](EncodedStr::as_bytes), which is not the number of
+    /// characters in encodings where some characters take several bytes.
+    fn len(&self) -> usize;
+
+    /// Returns `true` if the string has a length of zero bytes.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the encoded bytes of the string.
+    fn as_bytes(&self) -> &[u8];
+
+    /// Decodes the string into UTF-8.
+    fn to_utf8(&self) -> String;
+
+    /// Returns the name of the encoding, as registered by IANA when it is.
+    fn encoding_name(&self) -> &'static str;
+
+    /// Returns the character at `index`, counted in characters, or [`None`] if `index` is out of
+    /// bounds.
+    fn char_at(&self, index: usize) -> Option<char>;
+}
+
+impl EncodedStr for IsoLatin1Str {
+    #[inline]
+    fn len(&self) -> usize {
+        self.len()
+    }
+
+    #[inline]
+    fn as_bytes(&self) -> &[u8] {
+        self.as_bytes()
+    }
+
+    fn to_utf8(&self) -> String {
+        self.to_string()
+    }
+
+    #[inline]
+    fn encoding_name(&self) -> &'static str {
+        "ISO-8859-1"
+    }
+
+    #[inline]
+    fn char_at(&self, index: usize) -> Option<char> {
+        self.as_bytes().get(index).map(|&byte| char::from(byte))
+    }
+}
+
+impl<T: EncodedStr + ?Sized> EncodedStr for &T {
+    #[inline]
+    fn len(&self) -> usize {
+        (**self).len()
+    }
+
+    #[inline]
+    fn as_bytes(&self) -> &[u8] {
+        (**self).as_bytes()
+    }
+
+    fn to_utf8(&self) -> String {
+        (**self).to_utf8()
+    }
+
+    #[inline]
+    fn encoding_name(&self) -> &'static str {
+        (**self).encoding_name()
+    }
+
+    #[inline]
+    fn char_at(&self, index: usize) -> Option<char> {
+        (**self).char_at(index)
+    }
+}
+
+impl EncodedStr for IsoLatin1String {
+    #[inline]
+    fn len(&self) -> usize {
+        self.as_str().len()
+    }
+
+    #[inline]
+    fn as_bytes(&self) -> &[u8] {
+        self.as_str().as_bytes()
+    }
+
+    fn to_utf8(&self) -> String {
+        self.as_str().to_utf8()
+    }
+
+    #[inline]
+    fn encoding_name(&self) -> &'static str {
+        self.as_str().encoding_name()
+    }
+
+    #[inline]
+    fn char_at(&self, index: usize) -> Option<char> {
+        self.as_str().char_at(index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn trait_objects() {
+        let s = IsoLatin1Str::from_iso8859_1(b"\xC6gir").unwrap();
+        let owned = s.to_owned();
+        let strings: [&dyn EncodedStr; 2] = [&s, &owned];
+
+        for s in strings {
+            assert_eq!(s.len(), 4);
+            assert!(!s.is_empty());
+            assert_eq!(s.as_bytes(), b"\xC6gir");
+            assert_eq!(s.to_utf8(), "Ægir");
+            assert_eq!(s.encoding_name(), "ISO-8859-1");
+            assert_eq!(s.char_at(0), Some('Æ'));
+            assert_eq!(s.char_at(3), Some('r'));
+            assert_eq!(s.char_at(4), None);
+        }
+    }
+
+    #[test]
+    fn empty() {
+        let s: &dyn EncodedStr = &IsoLatin1String::new();
+        assert!(s.is_empty());
+        assert_eq!(s.to_utf8(), "");
+        assert_eq!(s.char_at(0), None);
+    }
+}
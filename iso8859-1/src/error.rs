@@ -0,0 +1,363 @@
+//! Crate-level error type.
+//!
+//! Every error produced by this crate can be converted into an [`EncodingError`], so applications
+//! dealing with many encodings can handle and report failures in a uniform way.
+
+use std::{error::Error, fmt};
+
+#[cfg(feature = "csv")]
+use crate::csv::FieldError;
+#[cfg(feature = "quick-xml")]
+use crate::xml::XmlError;
+use crate::{
+    FromIso8859_1Error, FromStrError, FromUtf16Error, Iso8859_1Error, IsoLatin1CharError,
+    LocatedError, UppercaseError,
+};
+
+/// Name of the encoding this crate implements, as registered by IANA.
+const ENCODING_NAME: &str = "ISO-8859-1";
+
+/// The direction of the conversion that failed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum EncodingErrorKind {
+    /// Failed to interpret a value as ISO8859-1 text.
+    Decode,
+    /// Failed to represent a Unicode character as ISO8859-1.
+    Encode,
+}
+
+/// A error that occurred while converting text from or into ISO8859-1.
+///
+/// All error types of this crate convert into this type with [`From`], keeping the original error
+/// available through [`Error::source`].
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use iso8859_1::{EncodingError, EncodingErrorKind, IsoLatin1String};
+///
+/// let err: EncodingError = IsoLatin1String::from_iso8859_1(vec![b'a', 0x85]).unwrap_err().into();
+///
+/// assert_eq!(err.kind(), EncodingErrorKind::Decode);
+/// assert_eq!(err.position(), Some(1));
+/// assert_eq!(err.value(), Some(0x85));
+/// assert_eq!(err.encoding(), "ISO-8859-1");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EncodingError {
+    kind: EncodingErrorKind,
+    position: Option<usize>,
+    value: Option<u32>,
+    source: Source,
+}
+
+/// The original error wrapped by a [`EncodingError`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Source {
+    Char(IsoLatin1CharError),
+    Str(Iso8859_1Error),
+    String(FromIso8859_1Error),
+    Uppercase(UppercaseError),
+    Utf16(FromUtf16Error),
+    Utf8(FromStrError),
+    Located(LocatedError),
+    #[cfg(feature = "quick-xml")]
+    Xml(XmlError),
+    #[cfg(feature = "csv")]
+    Field(FieldError),
+}
+
+impl EncodingError {
+    /// Returns whether the failed conversion was a decoding or an encoding.
+    pub fn kind(&self) -> EncodingErrorKind {
+        self.kind
+    }
+
+    /// Returns the index of the offending value in the input, if known.
+    pub fn position(&self) -> Option<usize> {
+        self.position
+    }
+
+    /// Returns the offending value, if known.
+    ///
+    /// For decoding errors this is the byte value, for encoding errors the Unicode code point.
+    pub fn value(&self) -> Option<u32> {
+        self.value
+    }
+
+    /// Returns the name of the encoding involved in the failed conversion.
+    pub fn encoding(&self) -> &'static str {
+        ENCODING_NAME
+    }
+}
+
+impl fmt::Display for EncodingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            EncodingErrorKind::Decode => write!(f, "failed to decode {ENCODING_NAME} text")?,
+            EncodingErrorKind::Encode => write!(f, "failed to encode text as {ENCODING_NAME}")?,
+        }
+
+        match (self.kind, self.value) {
+            (EncodingErrorKind::Decode, Some(value)) => write!(f, ": byte 0x{value:02X}")?,
+            (EncodingErrorKind::Encode, Some(value)) => write!(f, ": character U+{value:04X}")?,
+            (_, None) => {}
+        }
+
+        if let Some(position) = self.position {
+            write!(f, " at index {position}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Error for EncodingError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match &self.source {
+            Source::Char(err) => Some(err),
+            Source::Str(err) => Some(err),
+            Source::String(err) => Some(err),
+            Source::Uppercase(err) => Some(err),
+            Source::Utf16(err) => Some(err),
+            Source::Utf8(err) => Some(err),
+            Source::Located(err) => Some(err),
+            #[cfg(feature = "quick-xml")]
+            Source::Xml(err) => Some(err),
+            #[cfg(feature = "csv")]
+            Source::Field(err) => Some(err),
+        }
+    }
+}
+
+impl From<IsoLatin1CharError> for EncodingError {
+    /// Undefined bytes are reported as decoding errors, invalid characters as encoding errors.
+    fn from(err: IsoLatin1CharError) -> Self {
+        let kind = match err {
+            IsoLatin1CharError::Undefined => EncodingErrorKind::Decode,
+            IsoLatin1CharError::Invalid => EncodingErrorKind::Encode,
+        };
+
+        EncodingError {
+            kind,
+            position: None,
+            value: None,
+            source: Source::Char(err),
+        }
+    }
+}
+
+impl From<Iso8859_1Error> for EncodingError {
+    fn from(err: Iso8859_1Error) -> Self {
+        EncodingError {
+            kind: EncodingErrorKind::Decode,
+            position: Some(err.valid_up_to()),
+            value: Some(err.invalid_byte() as u32),
+            source: Source::Str(err),
+        }
+    }
+}
+
+impl From<FromIso8859_1Error> for EncodingError {
+    fn from(err: FromIso8859_1Error) -> Self {
+        EncodingError {
+            kind: EncodingErrorKind::Decode,
+            position: Some(err.valid_up_to()),
+            value: Some(err.invalid_byte() as u32),
+            source: Source::String(err),
+        }
+    }
+}
+
+impl From<UppercaseError> for EncodingError {
+    /// The offending value is the code point of the character that has no single ISO8859-1
+    /// uppercase form.
+    fn from(err: UppercaseError) -> Self {
+        EncodingError {
+            kind: EncodingErrorKind::Encode,
+            position: Some(err.index()),
+            value: Some(u32::from(char::from(err.char()))),
+            source: Source::Uppercase(err),
+        }
+    }
+}
+
+impl From<FromUtf16Error> for EncodingError {
+    /// The offending value is the UTF-16 code unit that could not be converted.
+    fn from(err: FromUtf16Error) -> Self {
+        EncodingError {
+            kind: EncodingErrorKind::Encode,
+            position: Some(err.valid_up_to()),
+            value: Some(u32::from(err.invalid_unit())),
+            source: Source::Utf16(err),
+        }
+    }
+}
+
+impl From<FromStrError> for EncodingError {
+    /// The offending value is the code point of the character that could not be encoded.
+    fn from(err: FromStrError) -> Self {
+        EncodingError {
+            kind: EncodingErrorKind::Encode,
+            position: Some(err.valid_up_to()),
+            value: Some(u32::from(err.char())),
+            source: Source::Utf8(err),
+        }
+    }
+}
+
+impl From<LocatedError> for EncodingError {
+    fn from(err: LocatedError) -> Self {
+        let error = err.error();
+        EncodingError {
+            kind: EncodingErrorKind::Decode,
+            position: Some(error.valid_up_to()),
+            value: Some(error.invalid_byte() as u32),
+            source: Source::Located(err),
+        }
+    }
+}
+
+#[cfg(feature = "quick-xml")]
+impl From<XmlError> for EncodingError {
+    /// The position is the one of the invalid byte or reference in the raw text. The offending
+    /// value is not known.
+    fn from(err: XmlError) -> Self {
+        EncodingError {
+            kind: EncodingErrorKind::Decode,
+            position: Some(err.position()),
+            value: None,
+            source: Source::Xml(err),
+        }
+    }
+}
+
+#[cfg(feature = "csv")]
+impl From<FieldError> for EncodingError {
+    /// The position is the index of the invalid byte in its field.
+    fn from(err: FieldError) -> Self {
+        let error = err.iso8859_1_error();
+        EncodingError {
+            kind: EncodingErrorKind::Decode,
+            position: Some(error.valid_up_to()),
+            value: Some(error.invalid_byte() as u32),
+            source: Source::Field(err),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{IsoLatin1Str, IsoLatin1String, UppercasePolicy};
+
+    #[test]
+    fn from_char_error() {
+        let err = EncodingError::from(IsoLatin1CharError::Undefined);
+        assert_eq!(err.kind(), EncodingErrorKind::Decode);
+        assert_eq!(err.position(), None);
+        assert_eq!(err.value(), None);
+
+        let err = EncodingError::from(IsoLatin1CharError::Invalid);
+        assert_eq!(err.kind(), EncodingErrorKind::Encode);
+        assert_eq!(err.to_string(), "failed to encode text as ISO-8859-1");
+    }
+
+    #[test]
+    fn from_string_error() {
+        let err = IsoLatin1String::from_iso8859_1(vec![0x41, 0x42, 0x87]).unwrap_err();
+        let err = EncodingError::from(err);
+        assert_eq!(err.kind(), EncodingErrorKind::Decode);
+        assert_eq!(err.position(), Some(2));
+        assert_eq!(err.value(), Some(0x87));
+        assert_eq!(
+            err.to_string(),
+            "failed to decode ISO-8859-1 text: byte 0x87 at index 2"
+        );
+    }
+
+    #[test]
+    fn from_str_error() {
+        let err = IsoLatin1Str::from_iso8859_1(&[0x41, 0x9A]).unwrap_err();
+        let err = EncodingError::from(err);
+        assert_eq!(err.kind(), EncodingErrorKind::Decode);
+        assert_eq!(err.position(), Some(1));
+        assert_eq!(err.value(), Some(0x9A));
+    }
+
+    #[test]
+    fn from_uppercase_error() {
+        let s = IsoLatin1Str::from_iso8859_1(b"ma\xDF").unwrap();
+        let err = s.to_uppercase(UppercasePolicy::Error).unwrap_err();
+        let err = EncodingError::from(err);
+        assert_eq!(err.kind(), EncodingErrorKind::Encode);
+        assert_eq!(err.position(), Some(2));
+        assert_eq!(err.value(), Some(0xDF));
+    }
+
+    #[test]
+    fn from_str_error_utf8() {
+        let err = "caf\u{E9} \u{20AC}".parse::<IsoLatin1String>().unwrap_err();
+        let err = EncodingError::from(err);
+        assert_eq!(err.kind(), EncodingErrorKind::Encode);
+        assert_eq!(err.position(), Some(6));
+        assert_eq!(err.value(), Some(0x20AC));
+        assert_eq!(
+            err.to_string(),
+            "failed to encode text as ISO-8859-1: character U+20AC at index 6"
+        );
+    }
+
+    #[test]
+    fn from_located_error() {
+        let bytes = b"ok\nab\x8F";
+        let err = IsoLatin1Str::from_iso8859_1(bytes)
+            .unwrap_err()
+            .locate(bytes);
+        let err = EncodingError::from(err);
+        assert_eq!(err.kind(), EncodingErrorKind::Decode);
+        assert_eq!(err.position(), Some(5));
+        assert_eq!(err.value(), Some(0x8F));
+        assert!(err.source().unwrap().is::<LocatedError>());
+    }
+
+    #[cfg(feature = "quick-xml")]
+    #[test]
+    fn from_xml_error() {
+        let text = quick_xml::events::BytesText::from_escaped("a &nope; b");
+        let err = crate::xml::text(&text).unwrap_err();
+        let err = EncodingError::from(err);
+        assert_eq!(err.kind(), EncodingErrorKind::Decode);
+        assert_eq!(err.position(), Some(2));
+        assert_eq!(err.value(), None);
+        assert!(err.source().unwrap().is::<XmlError>());
+    }
+
+    #[cfg(feature = "csv")]
+    #[test]
+    fn from_field_error() {
+        let record = ::csv::ByteRecord::from(vec![&b"ok"[..], b"x\x9D"]);
+        let err = crate::csv::fields(&record)
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap_err();
+        let err = EncodingError::from(err);
+        assert_eq!(err.kind(), EncodingErrorKind::Decode);
+        assert_eq!(err.position(), Some(1));
+        assert_eq!(err.value(), Some(0x9D));
+        assert!(err.source().unwrap().is::<FieldError>());
+    }
+
+    #[test]
+    fn source_chain() {
+        let err = IsoLatin1String::from_iso8859_1(vec![0x90]).unwrap_err();
+        let err = EncodingError::from(err);
+
+        let source = err.source().unwrap();
+        assert!(source.is::<FromIso8859_1Error>());
+
+        let root = source.source().unwrap();
+        assert_eq!(root.downcast_ref(), Some(&IsoLatin1CharError::Undefined));
+    }
+}
@@ -0,0 +1,307 @@
+//! C interface.
+//!
+//! Exposes validation, transcoding and an owned string handle as `extern "C"` functions, so C and
+//! C++ code can use this crate. The [`IsoLatin1String`] handle is opaque: C code only ever holds a
+//! pointer to it, created by one of the `iso8859_1_string_from_*` functions and released with
+//! [`iso8859_1_string_free`].
+//!
+//! Buffers are passed as a pointer and a length in bytes. A null pointer is accepted for an empty
+//! buffer.
+//!
+//! Functions returning a length report errors with [`ISO8859_1_ERROR`] (`SIZE_MAX`), which is
+//! never a valid length, so that `0` always means an empty result. Functions returning a handle
+//! report errors with null, and write [`ISO8859_1_ERROR`] as the error index when the input
+//! pointer itself is invalid.
+//!
+//! To link against these functions, build a `staticlib` or `cdylib` crate that depends on this
+//! one with the `ffi` feature enabled. A header can be generated with `cbindgen`.
+
+use std::{ptr, slice};
+
+use crate::{validate, IsoLatin1Str, IsoLatin1String};
+
+/// The length returned on error, `SIZE_MAX` in C.
+///
+/// No buffer can be that long, so it is never a valid length.
+pub const ISO8859_1_ERROR: usize = usize::MAX;
+
+/// Builds a byte slice from a C buffer, or `None` if `ptr` is null and `len` is not zero.
+///
+/// # Safety
+///
+/// Unless null, `ptr` must be valid for reads of `len` bytes for the lifetime `'a`.
+unsafe fn bytes<'a>(ptr: *const u8, len: usize) -> Option<&'a [u8]> {
+    if ptr.is_null() {
+        return if len == 0 { Some(&[]) } else { None };
+    }
+    Some(slice::from_raw_parts(ptr, len))
+}
+
+/// Returns the number of leading bytes of the buffer that are valid ISO8859-1.
+///
+/// The buffer is valid if the returned value is equal to `len`; otherwise it is the index of the
+/// first invalid byte. Returns [`ISO8859_1_ERROR`] if `ptr` is null and `len` is not zero.
+///
+/// # Safety
+///
+/// Unless null, `ptr` must be valid for reads of `len` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn iso8859_1_validate(ptr: *const u8, len: usize) -> usize {
+    match bytes(ptr, len).map(validate) {
+        Some(Ok(())) => len,
+        Some(Err(error)) => error.valid_up_to(),
+        None => ISO8859_1_ERROR,
+    }
+}
+
+/// Decodes a ISO8859-1 buffer into UTF-8.
+///
+/// Returns the length in bytes of the UTF-8 encoding, which is `0` for an empty input, or
+/// [`ISO8859_1_ERROR`] if the input is not valid ISO8859-1 or if `ptr` is null and `len` is not
+/// zero. The output is only written if `out_cap` is at least the returned length, so the
+/// function can be called with a null `out` first to find out the size of the buffer to allocate.
+/// Nothing is written past the returned length, in particular no nul terminator.
+///
+/// # Safety
+///
+/// Unless null, `ptr` must be valid for reads of `len` bytes and `out` must be valid for writes
+/// of `out_cap` bytes. The buffers must not overlap.
+#[no_mangle]
+pub unsafe extern "C" fn iso8859_1_to_utf8(
+    ptr: *const u8,
+    len: usize,
+    out: *mut u8,
+    out_cap: usize,
+) -> usize {
+    let s = match bytes(ptr, len).and_then(|bytes| IsoLatin1Str::from_iso8859_1(bytes).ok()) {
+        Some(s) => s,
+        None => return ISO8859_1_ERROR,
+    };
+
+    let utf8_len = s.chars().map(|char| char::from(char).len_utf8()).sum();
+    if !out.is_null() && out_cap >= utf8_len {
+        let out = slice::from_raw_parts_mut(out, utf8_len);
+        let mut written = 0;
+        for char in s.chars() {
+            written += char::from(char).encode_utf8(&mut out[written..]).len();
+        }
+    }
+
+    utf8_len
+}
+
+/// Creates a string from a ISO8859-1 buffer, copying it.
+///
+/// Returns null if the buffer is not valid ISO8859-1. In that case, if `error_index` is not null,
+/// the index of the first invalid byte is written to it, or [`ISO8859_1_ERROR`] if `ptr` is null
+/// and `len` is not zero.
+///
+/// # Safety
+///
+/// Unless null, `ptr` must be valid for reads of `len` bytes and `error_index` must be valid for
+/// writes.
+#[no_mangle]
+pub unsafe extern "C" fn iso8859_1_string_from_bytes(
+    ptr: *const u8,
+    len: usize,
+    error_index: *mut usize,
+) -> *mut IsoLatin1String {
+    let result = match bytes(ptr, len) {
+        Some(bytes) => IsoLatin1String::from_iso8859_1(bytes.to_vec()).map_err(|e| e.valid_up_to()),
+        None => Err(ISO8859_1_ERROR),
+    };
+    into_handle(result, error_index)
+}
+
+/// Creates a string by encoding a UTF-8 buffer into ISO8859-1.
+///
+/// Returns null if the buffer is not valid UTF-8 or contains a character that is not in
+/// ISO8859-1. In that case, if `error_index` is not null, the byte index of the invalid data in
+/// the UTF-8 buffer is written to it, or [`ISO8859_1_ERROR`] if `ptr` is null and `len` is not
+/// zero.
+///
+/// # Safety
+///
+/// Unless null, `ptr` must be valid for reads of `len` bytes and `error_index` must be valid for
+/// writes.
+#[no_mangle]
+pub unsafe extern "C" fn iso8859_1_string_from_utf8(
+    ptr: *const u8,
+    len: usize,
+    error_index: *mut usize,
+) -> *mut IsoLatin1String {
+    let result = match bytes(ptr, len).map(std::str::from_utf8) {
+        Some(Ok(text)) => text
+            .parse::<IsoLatin1String>()
+            .map_err(|err| err.valid_up_to()),
+        Some(Err(error)) => Err(error.valid_up_to()),
+        None => Err(ISO8859_1_ERROR),
+    };
+    into_handle(result, error_index)
+}
+
+/// Boxes a successfully created string, or reports the error index through `error_index`.
+///
+/// # Safety
+///
+/// Unless null, `error_index` must be valid for writes.
+unsafe fn into_handle(
+    result: Result<IsoLatin1String, usize>,
+    error_index: *mut usize,
+) -> *mut IsoLatin1String {
+    match result {
+        Ok(s) => Box::into_raw(Box::new(s)),
+        Err(index) => {
+            if !error_index.is_null() {
+                error_index.write(index);
+            }
+            ptr::null_mut()
+        }
+    }
+}
+
+/// Returns the length of the string in bytes, which is also its length in characters.
+///
+/// Returns `0` for a null handle.
+///
+/// # Safety
+///
+/// Unless null, `s` must be a handle returned by this module that was not freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn iso8859_1_string_len(s: *const IsoLatin1String) -> usize {
+    s.as_ref().map_or(0, |s| s.len())
+}
+
+/// Returns a pointer to the ISO8859-1 bytes of the string, which are not nul terminated.
+///
+/// The pointer is valid until the string is freed. Returns null for a null handle.
+///
+/// # Safety
+///
+/// Unless null, `s` must be a handle returned by this module that was not freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn iso8859_1_string_data(s: *const IsoLatin1String) -> *const u8 {
+    s.as_ref().map_or(ptr::null(), |s| s.as_bytes().as_ptr())
+}
+
+/// Frees a string. Does nothing for a null handle.
+///
+/// # Safety
+///
+/// Unless null, `s` must be a handle returned by this module that was not freed yet. It must not
+/// be used afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn iso8859_1_string_free(s: *mut IsoLatin1String) {
+    if !s.is_null() {
+        drop(Box::from_raw(s));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validate() {
+        let bytes = b"Gr\xFC\x85e";
+        unsafe {
+            assert_eq!(iso8859_1_validate(bytes.as_ptr(), 3), 3);
+            assert_eq!(iso8859_1_validate(bytes.as_ptr(), bytes.len()), 3);
+            assert_eq!(iso8859_1_validate(ptr::null(), 0), 0);
+            assert_eq!(iso8859_1_validate(bytes.as_ptr(), 0), 0);
+            assert_eq!(iso8859_1_validate(ptr::null(), 1), ISO8859_1_ERROR);
+        }
+    }
+
+    #[test]
+    fn to_utf8() {
+        let bytes = b"Gr\xFC\xDFe";
+        unsafe {
+            let len = iso8859_1_to_utf8(bytes.as_ptr(), bytes.len(), ptr::null_mut(), 0);
+            assert_eq!(len, "Grüße".len());
+
+            let mut out = vec![0; len];
+            let written = iso8859_1_to_utf8(bytes.as_ptr(), bytes.len(), out.as_mut_ptr(), len);
+            assert_eq!(written, len);
+            assert_eq!(out, "Grüße".as_bytes());
+
+            assert_eq!(
+                iso8859_1_to_utf8(b"\x85".as_ptr(), 1, out.as_mut_ptr(), len),
+                ISO8859_1_ERROR
+            );
+            assert_eq!(out, "Grüße".as_bytes());
+        }
+    }
+
+    #[test]
+    fn to_utf8_empty() {
+        let mut out = [0xFF; 4];
+        unsafe {
+            assert_eq!(iso8859_1_to_utf8(ptr::null(), 0, ptr::null_mut(), 0), 0);
+            assert_eq!(iso8859_1_to_utf8(b"".as_ptr(), 0, out.as_mut_ptr(), 4), 0);
+            assert_eq!(
+                iso8859_1_to_utf8(ptr::null(), 1, out.as_mut_ptr(), 4),
+                ISO8859_1_ERROR
+            );
+        }
+        assert_eq!(out, [0xFF; 4]);
+    }
+
+    #[test]
+    fn string_from_bytes() {
+        let mut error_index = usize::MAX;
+        unsafe {
+            let bytes = b"Gr\xFC\xDFe";
+            let s = iso8859_1_string_from_bytes(bytes.as_ptr(), bytes.len(), &mut error_index);
+            assert!(!s.is_null());
+            assert_eq!(iso8859_1_string_len(s), 5);
+            assert_eq!(slice::from_raw_parts(iso8859_1_string_data(s), 5), bytes);
+            iso8859_1_string_free(s);
+            assert_eq!(error_index, usize::MAX);
+
+            let bytes = b"Gr\x85";
+            let s = iso8859_1_string_from_bytes(bytes.as_ptr(), bytes.len(), &mut error_index);
+            assert!(s.is_null());
+            assert_eq!(error_index, 2);
+
+            let s = iso8859_1_string_from_bytes(ptr::null(), 1, &mut error_index);
+            assert!(s.is_null());
+            assert_eq!(error_index, ISO8859_1_ERROR);
+        }
+    }
+
+    #[test]
+    fn string_from_utf8() {
+        let mut error_index = usize::MAX;
+        unsafe {
+            let text = "Grüße";
+            let s = iso8859_1_string_from_utf8(text.as_ptr(), text.len(), &mut error_index);
+            assert_eq!(
+                slice::from_raw_parts(iso8859_1_string_data(s), 5),
+                b"Gr\xFC\xDFe"
+            );
+            iso8859_1_string_free(s);
+
+            let text = "Grüß €";
+            let s = iso8859_1_string_from_utf8(text.as_ptr(), text.len(), &mut error_index);
+            assert!(s.is_null());
+            assert_eq!(error_index, 7);
+
+            let s = iso8859_1_string_from_utf8(b"a\xFF".as_ptr(), 2, ptr::null_mut());
+            assert!(s.is_null());
+
+            let s = iso8859_1_string_from_utf8(ptr::null(), 1, &mut error_index);
+            assert!(s.is_null());
+            assert_eq!(error_index, ISO8859_1_ERROR);
+        }
+    }
+
+    #[test]
+    fn null_handles() {
+        unsafe {
+            assert_eq!(iso8859_1_string_len(ptr::null()), 0);
+            assert!(iso8859_1_string_data(ptr::null()).is_null());
+            iso8859_1_string_free(ptr::null_mut());
+        }
+    }
+}
@@ -0,0 +1,395 @@
+//! Iterators over [`IsoLatin1Str`].
+
+use std::{iter::FusedIterator, slice};
+
+use crate::{IsoLatin1Char, IsoLatin1Str};
+
+/// An iterator over the [`IsoLatin1Char`]s of a string slice.
+///
+/// This struct is created by the [`chars`] method on [`IsoLatin1Str`]. See its documentation for
+/// more.
+///
+/// [`chars`]: IsoLatin1Str::chars
+#[derive(Clone, Debug)]
+pub struct Chars<'a> {
+    iter: slice::Iter<'a, u8>,
+}
+
+impl<'a> Chars<'a> {
+    pub(crate) fn new(s: &'a IsoLatin1Str) -> Self {
+        Chars {
+            iter: s.as_bytes().iter(),
+        }
+    }
+
+    /// Views the underlying data as a subslice of the original data.
+    pub fn as_str(&self) -> &'a IsoLatin1Str {
+        // SAFETY: the remaining bytes are a subslice of a valid `IsoLatin1Str`.
+        unsafe { IsoLatin1Str::from_iso8859_1_unchecked(self.iter.as_slice()) }
+    }
+}
+
+impl Iterator for Chars<'_> {
+    type Item = IsoLatin1Char;
+
+    #[inline]
+    fn next(&mut self) -> Option<IsoLatin1Char> {
+        self.iter.next().map(|&byte| IsoLatin1Char(byte))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.iter.len()
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<IsoLatin1Char> {
+        self.iter.nth(n).map(|&byte| IsoLatin1Char(byte))
+    }
+
+    #[inline]
+    fn last(mut self) -> Option<IsoLatin1Char> {
+        self.next_back()
+    }
+}
+
+impl DoubleEndedIterator for Chars<'_> {
+    #[inline]
+    fn next_back(&mut self) -> Option<IsoLatin1Char> {
+        self.iter.next_back().map(|&byte| IsoLatin1Char(byte))
+    }
+}
+
+impl ExactSizeIterator for Chars<'_> {}
+
+impl FusedIterator for Chars<'_> {}
+
+/// An iterator over the [`IsoLatin1Char`]s of a string slice, and their positions.
+///
+/// This struct is created by the [`char_indices`] method on [`IsoLatin1Str`]. See its
+/// documentation for more.
+///
+/// [`char_indices`]: IsoLatin1Str::char_indices
+#[derive(Clone, Debug)]
+pub struct CharIndices<'a> {
+    front_offset: usize,
+    iter: Chars<'a>,
+}
+
+impl<'a> CharIndices<'a> {
+    pub(crate) fn new(s: &'a IsoLatin1Str) -> Self {
+        CharIndices {
+            front_offset: 0,
+            iter: s.chars(),
+        }
+    }
+
+    /// Views the underlying data as a subslice of the original data.
+    pub fn as_str(&self) -> &'a IsoLatin1Str {
+        self.iter.as_str()
+    }
+
+    /// Returns the byte position of the next character, or the length of the underlying string
+    /// if there are no more characters.
+    pub fn offset(&self) -> usize {
+        self.front_offset
+    }
+}
+
+impl Iterator for CharIndices<'_> {
+    type Item = (usize, IsoLatin1Char);
+
+    #[inline]
+    fn next(&mut self) -> Option<(usize, IsoLatin1Char)> {
+        let char = self.iter.next()?;
+        let index = self.front_offset;
+        self.front_offset += 1;
+        Some((index, char))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.iter.count()
+    }
+
+    #[inline]
+    fn last(mut self) -> Option<(usize, IsoLatin1Char)> {
+        self.next_back()
+    }
+}
+
+impl DoubleEndedIterator for CharIndices<'_> {
+    #[inline]
+    fn next_back(&mut self) -> Option<(usize, IsoLatin1Char)> {
+        let char = self.iter.next_back()?;
+        Some((self.front_offset + self.iter.len(), char))
+    }
+}
+
+impl ExactSizeIterator for CharIndices<'_> {}
+
+impl FusedIterator for CharIndices<'_> {}
+
+/// An iterator over the bytes of a string slice.
+///
+/// This struct is created by the [`bytes`] method on [`IsoLatin1Str`]. See its documentation for
+/// more.
+///
+/// [`bytes`]: IsoLatin1Str::bytes
+#[derive(Clone, Debug)]
+pub struct Bytes<'a> {
+    iter: slice::Iter<'a, u8>,
+}
+
+impl<'a> Bytes<'a> {
+    pub(crate) fn new(s: &'a IsoLatin1Str) -> Self {
+        Bytes {
+            iter: s.as_bytes().iter(),
+        }
+    }
+}
+
+impl Iterator for Bytes<'_> {
+    type Item = u8;
+
+    #[inline]
+    fn next(&mut self) -> Option<u8> {
+        self.iter.next().copied()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.iter.len()
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<u8> {
+        self.iter.nth(n).copied()
+    }
+
+    #[inline]
+    fn last(mut self) -> Option<u8> {
+        self.next_back()
+    }
+}
+
+impl DoubleEndedIterator for Bytes<'_> {
+    #[inline]
+    fn next_back(&mut self) -> Option<u8> {
+        self.iter.next_back().copied()
+    }
+}
+
+impl ExactSizeIterator for Bytes<'_> {}
+
+impl FusedIterator for Bytes<'_> {}
+
+/// An iterator over the lines of a string slice.
+///
+/// This struct is created by the [`lines`] method on [`IsoLatin1Str`]. See its documentation for
+/// more.
+///
+/// [`lines`]: IsoLatin1Str::lines
+#[derive(Clone, Debug)]
+pub struct Lines<'a> {
+    iter: slice::SplitInclusive<'a, u8, fn(&u8) -> bool>,
+}
+
+impl<'a> Lines<'a> {
+    pub(crate) fn new(s: &'a IsoLatin1Str) -> Self {
+        Lines {
+            iter: s.as_bytes().split_inclusive(is_newline),
+        }
+    }
+}
+
+fn is_newline(byte: &u8) -> bool {
+    *byte == b'\n'
+}
+
+/// Removes the line terminator, either `\n` or `\r\n`, from the end of `line`.
+fn strip_line_ending(line: &[u8]) -> &IsoLatin1Str {
+    let line = match line {
+        [rest @ .., b'\r', b'\n'] | [rest @ .., b'\n'] => rest,
+        _ => line,
+    };
+
+    // SAFETY: `line` is a subslice of a valid `IsoLatin1Str`.
+    unsafe { IsoLatin1Str::from_iso8859_1_unchecked(line) }
+}
+
+impl<'a> Iterator for Lines<'a> {
+    type Item = &'a IsoLatin1Str;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a IsoLatin1Str> {
+        self.iter.next().map(strip_line_ending)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a> DoubleEndedIterator for Lines<'a> {
+    #[inline]
+    fn next_back(&mut self) -> Option<&'a IsoLatin1Str> {
+        self.iter.next_back().map(strip_line_ending)
+    }
+}
+
+impl FusedIterator for Lines<'_> {}
+
+/// An iterator over the non-whitespace substrings of a string, separated by any amount of
+/// whitespace.
+///
+/// This struct is created by the [`split_whitespace`] method on [`IsoLatin1Str`]. See its
+/// documentation for more.
+///
+/// [`split_whitespace`]: IsoLatin1Str::split_whitespace
+#[derive(Clone, Debug)]
+pub struct SplitWhitespace<'a> {
+    iter: slice::Split<'a, u8, fn(&u8) -> bool>,
+}
+
+impl<'a> SplitWhitespace<'a> {
+    pub(crate) fn new(s: &'a IsoLatin1Str) -> Self {
+        SplitWhitespace {
+            iter: s.as_bytes().split(is_whitespace),
+        }
+    }
+}
+
+fn is_whitespace(byte: &u8) -> bool {
+    IsoLatin1Char(*byte).is_whitespace()
+}
+
+/// Converts a non-empty split part into a string slice.
+fn non_empty(part: &[u8]) -> Option<&IsoLatin1Str> {
+    if part.is_empty() {
+        return None;
+    }
+
+    // SAFETY: `part` is a subslice of a valid `IsoLatin1Str`.
+    Some(unsafe { IsoLatin1Str::from_iso8859_1_unchecked(part) })
+}
+
+impl<'a> Iterator for SplitWhitespace<'a> {
+    type Item = &'a IsoLatin1Str;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a IsoLatin1Str> {
+        self.iter.by_ref().find_map(non_empty)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
+
+impl<'a> DoubleEndedIterator for SplitWhitespace<'a> {
+    #[inline]
+    fn next_back(&mut self) -> Option<&'a IsoLatin1Str> {
+        self.iter.by_ref().rev().find_map(non_empty)
+    }
+}
+
+impl FusedIterator for SplitWhitespace<'_> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{IsoLatin1Str, IsoLatin1String};
+
+    fn latin1(bytes: &[u8]) -> &IsoLatin1Str {
+        IsoLatin1Str::from_iso8859_1(bytes).unwrap()
+    }
+
+    #[test]
+    fn chars() {
+        let s = latin1(b"\xC6gir");
+        let chars: Vec<char> = s.chars().map(char::from).collect();
+        assert_eq!(chars, ['Æ', 'g', 'i', 'r']);
+
+        let rev: Vec<char> = s.chars().rev().map(char::from).collect();
+        assert_eq!(rev, ['r', 'i', 'g', 'Æ']);
+
+        let mut chars = s.chars();
+        chars.next();
+        assert_eq!(chars.len(), 3);
+        assert_eq!(chars.as_str(), latin1(b"gir"));
+    }
+
+    #[test]
+    fn char_indices() {
+        let s = latin1(b"\xC6gir");
+        let indices: Vec<(usize, char)> = s.char_indices().map(|(i, c)| (i, c.into())).collect();
+        assert_eq!(indices, [(0, 'Æ'), (1, 'g'), (2, 'i'), (3, 'r')]);
+
+        let mut iter = s.char_indices();
+        assert_eq!(iter.next_back().map(|(i, _)| i), Some(3));
+        assert_eq!(iter.next().map(|(i, _)| i), Some(0));
+        assert_eq!(iter.next_back().map(|(i, _)| i), Some(2));
+        assert_eq!(iter.offset(), 1);
+    }
+
+    #[test]
+    fn bytes() {
+        let s = latin1(b"\xC6gir");
+        assert_eq!(s.bytes().collect::<Vec<_>>(), [0xC6, b'g', b'i', b'r']);
+        assert_eq!(s.bytes().next_back(), Some(b'r'));
+    }
+
+    #[test]
+    fn lines() {
+        let s = latin1(b"one\r\ntw\xF6\n\nthree\r");
+        let lines: Vec<&[u8]> = s.lines().map(IsoLatin1Str::as_bytes).collect();
+        assert_eq!(lines, [&b"one"[..], b"tw\xF6", b"", b"three\r"]);
+
+        let rev: Vec<&[u8]> = s.lines().rev().map(IsoLatin1Str::as_bytes).collect();
+        assert_eq!(rev, [&b"three\r"[..], b"", b"tw\xF6", b"one"]);
+
+        assert_eq!(latin1(b"").lines().next(), None);
+        assert_eq!(latin1(b"\n").lines().count(), 1);
+    }
+
+    #[test]
+    fn split_whitespace() {
+        let s = latin1(b"  a\xA0\xA0b\tc\r\n d ");
+        let words: Vec<&[u8]> = s.split_whitespace().map(IsoLatin1Str::as_bytes).collect();
+        assert_eq!(words, [&b"a"[..], b"b", b"c", b"d"]);
+
+        let rev: Vec<&[u8]> = s
+            .split_whitespace()
+            .rev()
+            .map(IsoLatin1Str::as_bytes)
+            .collect();
+        assert_eq!(rev, [&b"d"[..], b"c", b"b", b"a"]);
+
+        assert_eq!(latin1(b" \t\xA0").split_whitespace().next(), None);
+    }
+
+    #[test]
+    fn string_deref() {
+        let s = IsoLatin1String::from_iso8859_1(b"h\xE6j du".to_vec()).unwrap();
+        assert_eq!(s.chars().count(), 6);
+        assert_eq!(s.split_whitespace().count(), 2);
+        assert_eq!(s.lines().count(), 1);
+    }
+}
@@ -2,16 +2,88 @@
 //!
 //! This crate provides string and character types that are encoded in ISO8859-1.
 
-use std::fmt;
+use std::{borrow::Borrow, fmt, mem, ops};
+
+// Lets the `latin1!` macro expansion, which refers to `::iso8859_1`, work inside this crate too.
+#[cfg(feature = "macros")]
+extern crate self as iso8859_1;
+
+#[cfg(feature = "bincode")]
+mod bincode_impls;
+mod case;
+mod cmp;
+mod compose;
+pub mod cp1252;
+#[cfg(feature = "csv")]
+pub mod csv;
+mod encode;
+mod encoded;
+mod error;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+mod iter;
+mod location;
+pub mod mixed;
+pub mod mojibake;
+#[cfg(feature = "pyo3")]
+mod python;
+pub mod rfc5322;
+mod sanitize;
+mod translit;
+mod utf16;
+mod whitespace;
+mod width;
+#[cfg(feature = "quick-xml")]
+pub mod xml;
+
+pub use case::{DisplayLowercase, DisplayUppercase, ToUppercase, UppercaseError, UppercasePolicy};
+pub use encode::FromStrError;
+pub use encoded::EncodedStr;
+pub use error::{EncodingError, EncodingErrorKind};
+pub use iter::{Bytes, CharIndices, Chars, Lines, SplitWhitespace};
+pub use location::LocatedError;
+pub use sanitize::SanitizePolicy;
+pub use translit::Transliteration;
+pub use utf16::{EncodeUtf16, FromUtf16Error};
+
+/// Creates a `&'static IsoLatin1Str` from a string literal.
+///
+/// The literal is validated and encoded at compile time, so using it has no runtime cost and can
+/// be done in constants and statics. A character that can't be encoded in ISO8859-1 is a compile
+/// error.
+///
+/// This macro is only available with the `macros` feature, which is enabled by default.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use iso8859_1::{latin1, IsoLatin1Str};
+///
+/// const GREETING: &IsoLatin1Str = latin1!("Grüße");
+///
+/// assert_eq!(GREETING.as_bytes(), b"Gr\xFC\xDFe");
+/// ```
+///
+/// Characters outside of ISO8859-1 do not compile:
+///
+/// ```compile_fail
+/// use iso8859_1::latin1;
+///
+/// let s = latin1!("Łódź");
+/// ```
+#[cfg(feature = "macros")]
+pub use iso8859_1_macros::latin1;
 
-/// A single ISO8859-10 character.
+/// A single ISO8859-1 character.
 ///
 /// # Validity
-/// A `IsoLatin6Char` is valid if it is a valid well defined ISO8859-10 character or ASCII control
+/// A `IsoLatin1Char` is valid if it is a valid well defined ISO8859-1 character or ASCII control
 /// codes.
 ///
 /// ## Why ASCII control codes are valid?
-/// Although ISO8859-10 does not define ASCII control codes values (`0x00` to `0x1F`), we consider
+/// Although ISO8859-1 does not define ASCII control codes values (`0x00` to `0x1F`), we consider
 /// them valid for convenience.
 ///
 /// Since these code values are considered undefined by the standard, the decision on what to do
@@ -23,6 +95,39 @@ pub struct IsoLatin1Char(u8);
 
 // Public API
 impl IsoLatin1Char {
+    /// Converts a byte to a `IsoLatin1Char` in a const context.
+    ///
+    /// This allows declaring validated constants and statics without going through
+    /// [`TryFrom<u8>`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the byte is undefined in ISO8859-1. When evaluated in a const context, this is
+    /// a compile-time error.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use iso8859_1::IsoLatin1Char;
+    ///
+    /// const SECTION: IsoLatin1Char = IsoLatin1Char::from_u8_const(0xA7);
+    /// assert_eq!(SECTION, '§');
+    /// ```
+    ///
+    /// ```compile_fail
+    /// use iso8859_1::IsoLatin1Char;
+    ///
+    /// const UNDEFINED: IsoLatin1Char = IsoLatin1Char::from_u8_const(0x85);
+    /// ```
+    pub const fn from_u8_const(byte: u8) -> IsoLatin1Char {
+        if let 0x80..=0x9F = byte {
+            panic!("byte undefined in ISO8859-1");
+        }
+        IsoLatin1Char(byte)
+    }
+
     /// Returns `true` if this character has the `Alphabetic` property.
     ///
     /// `Alphabetic` is described in Chapter 4 (Character Properties) of the [Unicode Standard] and
@@ -39,9 +144,26 @@ impl IsoLatin1Char {
     ///
     /// Basic usage:
     ///
-    /// TODO
+    /// ```
+    /// use iso8859_1::IsoLatin1Char;
+    ///
+    /// assert!(IsoLatin1Char::try_from('a').unwrap().is_alphabetic());
+    /// assert!(IsoLatin1Char::try_from('Þ').unwrap().is_alphabetic());
+    /// assert!(!IsoLatin1Char::try_from('1').unwrap().is_alphabetic());
+    /// assert!(!IsoLatin1Char::try_from('×').unwrap().is_alphabetic());
+    /// ```
     pub fn is_alphabetic(&self) -> bool {
-        todo!()
+        match self.0 {
+            0x41..=0x5A |  // between A to Z
+            0x61..=0x7A |  // between a to z
+            0xAA |         // only ª
+            0xB5 |         // only µ
+            0xBA |         // only º
+            0xC0..=0xD6 |  // between À to Ö
+            0xD8..=0xF6 |  // between Ø to ö
+            0xF8..=0xFF => true, // between ø to ÿ
+            _ => false
+        }
     }
 
     /// Returns `true` if this character satisfies either [`is_alphabetic`] or [`is_numeric`].
@@ -50,7 +172,13 @@ impl IsoLatin1Char {
     ///
     /// Basic usage:
     ///
-    /// TODO
+    /// ```
+    /// use iso8859_1::IsoLatin1Char;
+    ///
+    /// assert!(IsoLatin1Char::try_from('Ð').unwrap().is_alphanumeric());
+    /// assert!(IsoLatin1Char::try_from('½').unwrap().is_alphanumeric());
+    /// assert!(!IsoLatin1Char::try_from('§').unwrap().is_alphanumeric());
+    /// ```
     pub fn is_alphanumeric(&self) -> bool {
         self.is_alphabetic() || self.is_numeric()
     }
@@ -68,13 +196,20 @@ impl IsoLatin1Char {
     /// [ucd]: https://www.unicode.org/reports/tr44/
     /// [`UnicodeData.txt`]: https://www.unicode.org/Public/UCD/latest/ucd/UnicodeData.txt
     ///
+    /// Only the ASCII control codes `0x00` to `0x1F` are considered control codes by this method.
+    ///
     /// # Examples
     ///
     /// Basic usage:
     ///
-    /// TODO
+    /// ```
+    /// use iso8859_1::IsoLatin1Char;
+    ///
+    /// assert!(IsoLatin1Char::try_from('\n').unwrap().is_control());
+    /// assert!(!IsoLatin1Char::try_from('q').unwrap().is_control());
+    /// ```
     pub fn is_control(&self) -> bool {
-        todo!()
+        self.0 <= 0x1F
     }
 
     /// Checks if a `char` is a digit in the given radix.
@@ -106,16 +241,32 @@ impl IsoLatin1Char {
     /// Basic usage:
     ///
     /// ```
-    /// TODO
+    /// use iso8859_1::IsoLatin1Char;
+    ///
+    /// assert!(IsoLatin1Char::try_from('1').unwrap().is_digit(10));
+    /// assert!(IsoLatin1Char::try_from('f').unwrap().is_digit(16));
+    /// assert!(!IsoLatin1Char::try_from('f').unwrap().is_digit(10));
     /// ```
     ///
     /// Passing a large radix, causing a panic:
     ///
     /// ```should_panic
-    /// TODO
+    /// use iso8859_1::IsoLatin1Char;
+    ///
+    /// // this panics
+    /// IsoLatin1Char::try_from('1').unwrap().is_digit(37);
     /// ```
     pub fn is_digit(&self, radix: u8) -> bool {
-        todo!()
+        assert!(radix <= 36, "is_digit: radix is too high (maximum 36)");
+
+        let digit = match self.0 {
+            0x30..=0x39 => self.0 - 0x30,
+            0x41..=0x5A => self.0 - 0x41 + 10,
+            0x61..=0x7A => self.0 - 0x61 + 10,
+            _ => return false,
+        };
+
+        digit < radix
     }
 
     /// Returns `true` if this character has one of the general categories for numbers.
@@ -136,7 +287,12 @@ impl IsoLatin1Char {
     /// Basic usage:
     ///
     /// ```
-    /// TODO
+    /// use iso8859_1::IsoLatin1Char;
+    ///
+    /// assert!(IsoLatin1Char::try_from('7').unwrap().is_numeric());
+    /// assert!(IsoLatin1Char::try_from('²').unwrap().is_numeric());
+    /// assert!(IsoLatin1Char::try_from('¾').unwrap().is_numeric());
+    /// assert!(!IsoLatin1Char::try_from('K').unwrap().is_numeric());
     /// ```
     pub fn is_numeric(&self) -> bool {
         match self.0 {
@@ -163,10 +319,17 @@ impl IsoLatin1Char {
     /// Basic usage:
     ///
     /// ```
-    /// TODO
+    /// use iso8859_1::IsoLatin1Char;
+    ///
+    /// assert!(IsoLatin1Char::try_from(' ').unwrap().is_whitespace());
+    ///
+    /// // a non-breaking space
+    /// assert!(IsoLatin1Char::try_from(0xA0_u8).unwrap().is_whitespace());
+    ///
+    /// assert!(!IsoLatin1Char::try_from('å').unwrap().is_whitespace());
     /// ```
     pub fn is_whitespace(&self) -> bool {
-        todo!()
+        matches!(self.0, 0x09..=0x0D | 0x20 | 0xA0)
     }
 
     /// Returns `true` if this character has the `Lowercase` property.
@@ -187,10 +350,22 @@ impl IsoLatin1Char {
     /// Basic usage:
     ///
     /// ```
-    /// TODO
+    /// use iso8859_1::IsoLatin1Char;
+    ///
+    /// assert!(IsoLatin1Char::try_from('a').unwrap().is_lowercase());
+    /// assert!(IsoLatin1Char::try_from('ß').unwrap().is_lowercase());
+    /// assert!(!IsoLatin1Char::try_from('Å').unwrap().is_lowercase());
     /// ```
     pub fn is_lowercase(&self) -> bool {
-        todo!()
+        match self.0 {
+            0x61..=0x7A |  // between a to z
+            0xAA |         // only ª
+            0xB5 |         // only µ
+            0xBA |         // only º
+            0xDF..=0xF6 |  // between ß to ö
+            0xF8..=0xFF => true, // between ø to ÿ
+            _ => false
+        }
     }
 
     /// Returns `true` if this character has the `Uppercase` property.
@@ -210,10 +385,90 @@ impl IsoLatin1Char {
     /// Basic usage:
     ///
     /// ```
-    /// TODO
+    /// use iso8859_1::IsoLatin1Char;
+    ///
+    /// assert!(IsoLatin1Char::try_from('A').unwrap().is_uppercase());
+    /// assert!(IsoLatin1Char::try_from('Å').unwrap().is_uppercase());
+    /// assert!(!IsoLatin1Char::try_from('ß').unwrap().is_uppercase());
     /// ```
     pub fn is_uppercase(&self) -> bool {
-        todo!()
+        match self.0 {
+            0x41..=0x5A |  // between A to Z
+            0xC0..=0xD6 |  // between À to Ö
+            0xD8..=0xDE => true, // between Ø to Þ
+            _ => false
+        }
+    }
+
+    /// Returns the lowercase equivalent of this character.
+    ///
+    /// Every uppercase character of ISO8859-1 has its lowercase form in ISO8859-1, so this
+    /// conversion never fails. Characters without a lowercase form are returned unchanged.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use iso8859_1::IsoLatin1Char;
+    ///
+    /// let upper = IsoLatin1Char::try_from('Æ').unwrap();
+    /// assert_eq!(char::from(upper.to_lowercase()), 'æ');
+    /// ```
+    pub fn to_lowercase(&self) -> IsoLatin1Char {
+        if self.is_uppercase() {
+            IsoLatin1Char(self.0 + 0x20)
+        } else {
+            *self
+        }
+    }
+
+    /// Returns an iterator that yields the uppercase equivalent of this character.
+    ///
+    /// The `policy` decides what happens to the characters that do not have a single ISO8859-1
+    /// uppercase form (`ß`, `ÿ` and `µ`), see [`UppercasePolicy`] for the options. Characters
+    /// without a uppercase form are returned unchanged.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] only if `policy` is [`UppercasePolicy::Error`] and this character does not
+    /// have a single ISO8859-1 uppercase form.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use iso8859_1::{IsoLatin1Char, UppercasePolicy};
+    ///
+    /// let lower = IsoLatin1Char::try_from('æ').unwrap();
+    /// let upper: String = lower.to_uppercase(UppercasePolicy::Error).unwrap().map(char::from).collect();
+    /// assert_eq!(upper, "Æ");
+    ///
+    /// let sharp_s = IsoLatin1Char::try_from('ß').unwrap();
+    /// let upper: String = sharp_s.to_uppercase(UppercasePolicy::Substitute).unwrap().map(char::from).collect();
+    /// assert_eq!(upper, "SS");
+    ///
+    /// assert!(sharp_s.to_uppercase(UppercasePolicy::Error).is_err());
+    /// ```
+    pub fn to_uppercase(&self, policy: UppercasePolicy) -> Result<ToUppercase, UppercaseError> {
+        let substitute = match self.0 {
+            // between a to z, à to þ without ÷
+            0x61..=0x7A | 0xE0..=0xF6 | 0xF8..=0xFE => {
+                return Ok(ToUppercase::one(IsoLatin1Char(self.0 - 0x20)))
+            }
+            0xDF => ToUppercase::two(IsoLatin1Char(b'S'), IsoLatin1Char(b'S')),
+            0xFF => ToUppercase::one(IsoLatin1Char(b'Y')),
+            // `M` would read as mega rather than micro, so µ is kept as it is.
+            0xB5 => ToUppercase::one(*self),
+            _ => return Ok(ToUppercase::one(*self)),
+        };
+
+        match policy {
+            UppercasePolicy::Keep => Ok(ToUppercase::one(*self)),
+            UppercasePolicy::Substitute => Ok(substitute),
+            UppercasePolicy::Error => Err(UppercaseError::new(*self, 0)),
+        }
     }
 }
 
@@ -224,7 +479,10 @@ impl IsoLatin1Char {
     /// # Examples
     ///
     /// ```
-    /// TODO
+    /// use iso8859_1::IsoLatin1Char;
+    ///
+    /// assert!(IsoLatin1Char::try_from('a').unwrap().is_ascii());
+    /// assert!(!IsoLatin1Char::try_from('ä').unwrap().is_ascii());
     /// ```
     pub fn is_ascii(&self) -> bool {
         self.0 <= 0x7F
@@ -234,28 +492,28 @@ impl IsoLatin1Char {
 impl fmt::Debug for IsoLatin1Char {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        todo!()
+        fmt::Debug::fmt(&char::from(*self), f)
     }
 }
 
 impl fmt::Display for IsoLatin1Char {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        todo!()
+        fmt::Display::fmt(&char::from(*self), f)
     }
 }
 
 impl fmt::LowerHex for IsoLatin1Char {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        todo!()
+        fmt::LowerHex::fmt(&self.0, f)
     }
 }
 
 impl fmt::UpperHex for IsoLatin1Char {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        todo!()
+        fmt::UpperHex::fmt(&self.0, f)
     }
 }
 
@@ -264,14 +522,17 @@ impl TryFrom<u8> for IsoLatin1Char {
 
     #[inline]
     fn try_from(byte: u8) -> Result<Self, Self::Error> {
-        todo!()
+        match byte {
+            0x80..=0x9F => Err(IsoLatin1CharError::Undefined),
+            _ => Ok(IsoLatin1Char(byte)),
+        }
     }
 }
 
 impl From<IsoLatin1Char> for u8 {
     #[inline]
     fn from(char: IsoLatin1Char) -> u8 {
-        todo!()
+        char.0
     }
 }
 
@@ -280,33 +541,99 @@ impl TryFrom<char> for IsoLatin1Char {
 
     #[inline]
     fn try_from(char: char) -> Result<Self, Self::Error> {
-        todo!()
+        match char as u32 {
+            0x80..=0x9F => Err(IsoLatin1CharError::Invalid),
+            code @ 0x00..=0xFF => Ok(IsoLatin1Char(code as u8)),
+            _ => Err(IsoLatin1CharError::Invalid),
+        }
     }
 }
 
 impl From<IsoLatin1Char> for char {
     #[inline]
     fn from(char: IsoLatin1Char) -> Self {
-        todo!()
+        // ISO8859-1 code values are the same as the first 256 Unicode code points.
+        char::from(char.0)
+    }
+}
+
+/// Converts a Unicode code point, as found in UTF-16 and UCS-2 code units, into a
+/// `IsoLatin1Char`.
+impl TryFrom<u16> for IsoLatin1Char {
+    type Error = IsoLatin1CharError;
+
+    #[inline]
+    fn try_from(code: u16) -> Result<Self, Self::Error> {
+        IsoLatin1Char::try_from(u32::from(code))
     }
 }
 
-/// Error type to represent possible reasons for a byte not being a valid [`IsoLatin6Char`].
+impl From<IsoLatin1Char> for u16 {
+    #[inline]
+    fn from(char: IsoLatin1Char) -> u16 {
+        u16::from(char.0)
+    }
+}
+
+/// Converts a Unicode code point, as found in numeric character references, into a
+/// `IsoLatin1Char`.
+///
+/// Unlike with [`char`], the value does not need to be a valid Unicode scalar value: surrogates
+/// and values above `0x10FFFF` are rejected like any other value out of ISO8859-1.
+impl TryFrom<u32> for IsoLatin1Char {
+    type Error = IsoLatin1CharError;
+
+    #[inline]
+    fn try_from(code: u32) -> Result<Self, Self::Error> {
+        match code {
+            0x80..=0x9F => Err(IsoLatin1CharError::Invalid),
+            0x00..=0xFF => Ok(IsoLatin1Char(code as u8)),
+            _ => Err(IsoLatin1CharError::Invalid),
+        }
+    }
+}
+
+impl From<IsoLatin1Char> for u32 {
+    #[inline]
+    fn from(char: IsoLatin1Char) -> u32 {
+        u32::from(char.0)
+    }
+}
+
+/// Error type to represent possible reasons for a byte not being a valid [`IsoLatin1Char`].
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum IsoLatin1CharError {
-    /// The byte is not defined as a specific character in ISO8859-10 and it's not ASCII control
+    /// The byte is not defined as a specific character in ISO8859-1 and it's not ASCII control
     /// codes.
     Undefined,
     /// The byte contains a invalid value.
     Invalid,
 }
+
+impl fmt::Display for IsoLatin1CharError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IsoLatin1CharError::Undefined => f.write_str("character undefined in ISO8859-1"),
+            IsoLatin1CharError::Invalid => f.write_str("invalid ISO8859-1 character value"),
+        }
+    }
+}
+
+impl std::error::Error for IsoLatin1CharError {}
 #[cfg(test)]
 mod api_tests {
     use super::*;
 
     #[test]
     fn is_alphabetic() {
-        todo!()
+        for byte in (0x00..=0x7F).chain(0xA0..=0xFF) {
+            let char = IsoLatin1Char(byte);
+            assert_eq!(
+                char.is_alphabetic(),
+                char::from(char).is_alphabetic(),
+                "0x{byte:x}"
+            );
+        }
     }
 
     #[test]
@@ -350,8 +677,7 @@ mod api_tests {
         let numerics: Vec<u8> = [
             [0x30..=0x39, 0xBC..=0xBE, 0xB2..=0xB3]
                 .into_iter()
-                .map(|range| range.collect::<Vec<_>>())
-                .flatten()
+                .flat_map(|range| range.collect::<Vec<_>>())
                 .collect(),
             vec![0xB9],
         ]
@@ -405,12 +731,12 @@ mod trait_tests {
     use super::*;
 
     static LAST_PART_OF_ISO8859: [char; 96] = [
-        '\u{A0}', 'Ą', 'Ē', 'Ģ', 'Ī', 'Ĩ', 'Ķ', '§', 'Ļ', 'Đ', 'Š', 'Ŧ', 'Ž', '\u{AD}', 'Ū', 'Ŋ',
-        '°', 'ą', 'ē', 'ģ', 'ī', 'ĩ', 'ķ', '·', 'ļ', 'đ', 'š', 'ŧ', 'ž', '―', 'ū', 'ŋ', 'Ā', 'Á',
-        'Â', 'Ã', 'Ä', 'Å', 'Æ', 'Į', 'Č', 'É', 'Ę', 'Ë', 'Ė', 'Í', 'Î', 'Ï', 'Ð', 'Ņ', 'Ō', 'Ó',
-        'Ô', 'Õ', 'Ö', 'Ũ', 'Ø', 'Ų', 'Ú', 'Û', 'Ü', 'Ý', 'Þ', 'ß', 'ā', 'á', 'â', 'ã', 'ä', 'å',
-        'æ', 'į', 'č', 'é', 'ę', 'ë', 'ė', 'í', 'î', 'ï', 'ð', 'ņ', 'ō', 'ó', 'ô', 'õ', 'ö', 'ũ',
-        'ø', 'ų', 'ú', 'û', 'ü', 'ý', 'þ', 'ĸ',
+        '\u{A0}', '¡', '¢', '£', '¤', '¥', '¦', '§', '¨', '©', 'ª', '«', '¬', '\u{AD}', '®', '¯',
+        '°', '±', '²', '³', '´', 'µ', '¶', '·', '¸', '¹', 'º', '»', '¼', '½', '¾', '¿', 'À', 'Á',
+        'Â', 'Ã', 'Ä', 'Å', 'Æ', 'Ç', 'È', 'É', 'Ê', 'Ë', 'Ì', 'Í', 'Î', 'Ï', 'Ð', 'Ñ', 'Ò', 'Ó',
+        'Ô', 'Õ', 'Ö', '×', 'Ø', 'Ù', 'Ú', 'Û', 'Ü', 'Ý', 'Þ', 'ß', 'à', 'á', 'â', 'ã', 'ä', 'å',
+        'æ', 'ç', 'è', 'é', 'ê', 'ë', 'ì', 'í', 'î', 'ï', 'ð', 'ñ', 'ò', 'ó', 'ô', 'õ', 'ö', '÷',
+        'ø', 'ù', 'ú', 'û', 'ü', 'ý', 'þ', 'ÿ',
     ];
 
     #[test]
@@ -421,18 +747,38 @@ mod trait_tests {
         let upcase_ash = IsoLatin1Char(0xC6);
         assert_eq!(format!("{:?}", upcase_ash), "'Æ'");
 
-        todo!()
+        let newline = IsoLatin1Char(b'\n');
+        assert_eq!(format!("{:?}", newline), "'\\n'");
+
+        let quote = IsoLatin1Char(b'\'');
+        assert_eq!(format!("{:?}", quote), "'\\''");
+
+        for (byte, char) in (0xA0..=0xFF).zip(LAST_PART_OF_ISO8859) {
+            assert_eq!(
+                format!("{:?}", IsoLatin1Char(byte)),
+                format!("{:?}", char),
+                "0x{byte:x}"
+            );
+        }
     }
 
     #[test]
     fn display() {
         let upcase_a = IsoLatin1Char(0x41);
-        assert_eq!(format!("{:?}", upcase_a), "A");
+        assert_eq!(format!("{}", upcase_a), "A");
 
         let upcase_ash = IsoLatin1Char(0xC6);
-        assert_eq!(format!("{:?}", upcase_ash), "Æ");
+        assert_eq!(format!("{}", upcase_ash), "Æ");
+
+        assert_eq!(format!("{:>3}", upcase_ash), "  Æ");
 
-        todo!()
+        for (byte, char) in (0xA0..=0xFF).zip(LAST_PART_OF_ISO8859) {
+            assert_eq!(
+                format!("{}", IsoLatin1Char(byte)),
+                char.to_string(),
+                "0x{byte:x}"
+            );
+        }
     }
 
     #[test]
@@ -461,16 +807,35 @@ mod trait_tests {
 
     #[test]
     fn from_self_to_char() {
-        todo!()
+        for byte in 0x00..=0x7F {
+            assert_eq!(char::from(IsoLatin1Char(byte)), char::from(byte));
+        }
+
+        for (byte, char) in (0xA0..=0xFF).zip(LAST_PART_OF_ISO8859) {
+            assert_eq!(char::from(IsoLatin1Char(byte)), char, "0x{byte:x}");
+        }
+    }
+
+    #[test]
+    fn from_u8_const() {
+        const A: IsoLatin1Char = IsoLatin1Char::from_u8_const(b'A');
+        assert_eq!(A, IsoLatin1Char(b'A'));
+        assert_eq!(IsoLatin1Char::from_u8_const(0xFF), IsoLatin1Char(0xFF));
+    }
+
+    #[test]
+    #[should_panic(expected = "byte undefined in ISO8859-1")]
+    fn from_u8_const_undefined() {
+        IsoLatin1Char::from_u8_const(0x80);
     }
 
     #[test]
     fn try_from_u8_to_self() {
-        for byte in 0x00..=0x7F {
+        for byte in 0x00u8..=0x7F {
             assert!(IsoLatin1Char::try_from(byte).is_ok(), "0x{byte:x}");
         }
 
-        for byte in 0x80..=0x9F {
+        for byte in 0x80u8..=0x9F {
             assert_eq!(
                 IsoLatin1Char::try_from(byte),
                 Err(IsoLatin1CharError::Undefined),
@@ -478,7 +843,13 @@ mod trait_tests {
             );
         }
 
-        todo!()
+        for byte in 0xA0..=0xFF {
+            assert_eq!(
+                IsoLatin1Char::try_from(byte),
+                Ok(IsoLatin1Char(byte)),
+                "0x{byte:x}"
+            );
+        }
     }
 
     #[test]
@@ -495,87 +866,1040 @@ mod trait_tests {
             );
         }
 
-        todo!()
+        for (byte, char) in (0xA0..=0xFF).zip(LAST_PART_OF_ISO8859) {
+            assert_eq!(
+                IsoLatin1Char::try_from(char),
+                Ok(IsoLatin1Char(byte)),
+                "{char}"
+            );
+        }
+
+        for char in ['Ā', 'Ŋ', '€', '“', '\u{10FFFF}'] {
+            assert_eq!(
+                IsoLatin1Char::try_from(char),
+                Err(IsoLatin1CharError::Invalid),
+                "{char}"
+            );
+        }
+    }
+
+    #[test]
+    fn try_from_code_point_to_self() {
+        for code in (0x00..=0x7F).chain(0xA0..=0xFF) {
+            let expected = IsoLatin1Char(code as u8);
+            assert_eq!(
+                IsoLatin1Char::try_from(code as u16),
+                Ok(expected),
+                "0x{code:x}"
+            );
+            assert_eq!(IsoLatin1Char::try_from(code), Ok(expected), "0x{code:x}");
+            assert_eq!(u16::from(expected), code as u16);
+            assert_eq!(u32::from(expected), code);
+        }
+
+        for code in (0x80..=0x9F).chain([0x100, 0x14A, 0x20AC, 0xD800, 0xFFFF]) {
+            assert_eq!(
+                IsoLatin1Char::try_from(code as u16),
+                Err(IsoLatin1CharError::Invalid),
+                "0x{code:x}"
+            );
+        }
+
+        for code in [0x80, 0x9F, 0x100, 0xD800, 0x10FFFF, 0x110000, u32::MAX] {
+            assert_eq!(
+                IsoLatin1Char::try_from(code),
+                Err(IsoLatin1CharError::Invalid),
+                "0x{code:x}"
+            );
+        }
     }
 }
 
-/// A ISO8859-1 encoded, growable string.
+/// A ISO8859-1 encoded string slice.
+///
+/// This is the borrowed counterpart of [`IsoLatin1String`], the same way [`str`] is to
+/// [`String`]. Every byte of a `IsoLatin1Str` is a valid [`IsoLatin1Char`], so indexing by byte is
+/// indexing by character.
 ///
 /// # Examples
-/// TODO
 ///
-/// # ISO8859-1
-/// TODO
-#[derive(Clone, PartialEq, Eq, Hash)]
-pub struct IsoLatin1String {
-    bytes: Vec<u8>,
+/// Basic usage:
+///
+/// ```
+/// use iso8859_1::IsoLatin1Str;
+///
+/// let s = IsoLatin1Str::from_iso8859_1(b"Gr\xFC\xDFe").unwrap();
+/// assert_eq!(s.len(), 5);
+/// ```
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct IsoLatin1Str {
+    bytes: [u8],
 }
 
-impl IsoLatin1String {
-    /// Docs: TODO
-    /// Tip: You can use the docs of `std::string::String` to get a better idea and inspiration
-    pub const fn new() -> Self {
-        todo!()
-    }
+impl IsoLatin1Str {
+    /// Converts a slice of bytes to a `IsoLatin1Str`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] if any byte is undefined in ISO8859-1, with the position of the first
+    /// invalid byte.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use iso8859_1::IsoLatin1Str;
+    ///
+    /// assert!(IsoLatin1Str::from_iso8859_1(b"caf\xE9").is_ok());
+    ///
+    /// let err = IsoLatin1Str::from_iso8859_1(b"caf\x82").unwrap_err();
+    /// assert_eq!(err.valid_up_to(), 3);
+    /// ```
+    pub fn from_iso8859_1(bytes: &[u8]) -> Result<&Self, Iso8859_1Error> {
+        validate(bytes)?;
 
-    /// Docs: TODO
-    /// Tip: You can use the docs of `std::string::String` to get a better idea and inspiration
-    pub fn with_capacity(capacity: usize) -> Self {
-        todo!()
+        // SAFETY: just validated.
+        Ok(unsafe { IsoLatin1Str::from_iso8859_1_unchecked(bytes) })
     }
 
-    /// Docs: TODO
-    /// Tip: You can use the docs of `std::string::String` to get a better idea and inspiration
-    pub fn from_iso8859_1(vec: Vec<u8>) -> Result<Self, FromIso8859_1Error> {
-        todo!()
+    /// Converts a slice of bytes to a `IsoLatin1Str` without checking that it is valid
+    /// ISO8859-1.
+    ///
+    /// # Safety
+    ///
+    /// The bytes passed in must not contain bytes in the undefined `0x80` to `0x9F` range.
+    pub const unsafe fn from_iso8859_1_unchecked(bytes: &[u8]) -> &Self {
+        // SAFETY: `IsoLatin1Str` is `repr(transparent)` over `[u8]`.
+        mem::transmute::<&[u8], &IsoLatin1Str>(bytes)
     }
 
-    /// Docs: TODO
-    /// Tip: You can use the docs of `std::string::String` to get a better idea and inspiration
-    pub fn into_bytes(self) -> Vec<u8> {
-        todo!()
-    }
+    /// Converts a slice of bytes to a `IsoLatin1Str` in a const context.
+    ///
+    /// This allows declaring validated constants and statics without the `latin1!` macro or
+    /// `unsafe`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any byte is undefined in ISO8859-1. When evaluated in a const context, this is a
+    /// compile-time error.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use iso8859_1::IsoLatin1Str;
+    ///
+    /// static GREETING: &IsoLatin1Str = IsoLatin1Str::from_iso8859_1_const(b"Ol\xE1!");
+    /// assert_eq!(GREETING, "Olá!");
+    /// ```
+    ///
+    /// ```compile_fail
+    /// use iso8859_1::IsoLatin1Str;
+    ///
+    /// static UNDEFINED: &IsoLatin1Str = IsoLatin1Str::from_iso8859_1_const(b"\x85");
+    /// ```
+    pub const fn from_iso8859_1_const(bytes: &[u8]) -> &Self {
+        let mut index = 0;
+        while index < bytes.len() {
+            if let 0x80..=0x9F = bytes[index] {
+                panic!("byte undefined in ISO8859-1");
+            }
+            index += 1;
+        }
 
-    /// Docs: TODO
-    /// Tip: You can use the docs of `std::string::String` to get a better idea and inspiration
-    pub const fn capacity(&self) -> usize {
-        todo!()
+        // SAFETY: just validated.
+        unsafe { IsoLatin1Str::from_iso8859_1_unchecked(bytes) }
     }
 
-    /// Docs: TODO
-    /// Tip: You can use the docs of `std::string::String` to get a better idea and inspiration
-    pub fn reserve(&mut self, additional: usize) {
-        todo!()
+    /// Returns the length of `self`, in bytes, which is also its length in characters.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.bytes.len()
     }
 
-    /// Docs: TODO
-    /// Tip: You can use the docs of `std::string::String` to get a better idea and inspiration
-    pub fn reserve_exact(&mut self, additional: usize) {
-        todo!()
+    /// Returns `true` if `self` has a length of zero bytes.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
     }
 
-    // You guys got the idea. Try to replicate the String API into the type here.
-}
+    /// Converts a string slice to a byte slice.
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
 
-impl fmt::Debug for IsoLatin1String {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        // TIP: Usually for string types the debug implementation is the same as the display
-        // implementation but with double quote before and after the text.
-        todo!()
+    /// Returns an iterator over the [`IsoLatin1Char`]s of a string slice.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use iso8859_1::IsoLatin1Str;
+    ///
+    /// let s = IsoLatin1Str::from_iso8859_1(b"\xC5s").unwrap();
+    /// let mut chars = s.chars();
+    ///
+    /// assert_eq!(chars.next().map(char::from), Some('Å'));
+    /// assert_eq!(chars.next().map(char::from), Some('s'));
+    /// assert_eq!(chars.next(), None);
+    /// ```
+    #[inline]
+    pub fn chars(&self) -> Chars<'_> {
+        Chars::new(self)
     }
-}
 
-impl fmt::Display for IsoLatin1String {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        todo!()
+    /// Returns an iterator over the [`IsoLatin1Char`]s of a string slice, and their positions.
+    ///
+    /// Since every character is one byte long, the position is both the byte and the character
+    /// index.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use iso8859_1::IsoLatin1Str;
+    ///
+    /// let s = IsoLatin1Str::from_iso8859_1(b"\xC5s").unwrap();
+    /// let mut char_indices = s.char_indices();
+    ///
+    /// assert_eq!(char_indices.next().map(|(i, c)| (i, char::from(c))), Some((0, 'Å')));
+    /// assert_eq!(char_indices.next().map(|(i, c)| (i, char::from(c))), Some((1, 's')));
+    /// assert_eq!(char_indices.next(), None);
+    /// ```
+    #[inline]
+    pub fn char_indices(&self) -> CharIndices<'_> {
+        CharIndices::new(self)
     }
-}
 
-/// Docs: TODO
-/// Tip: You can use the docs of `std::string::String` to get a better idea and inspiration
-#[derive(Debug)]
-pub struct FromIso8859_1Error {
-    // TODO
+    /// Returns an iterator over the bytes of a string slice.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use iso8859_1::IsoLatin1Str;
+    ///
+    /// let s = IsoLatin1Str::from_iso8859_1(b"\xC5s").unwrap();
+    /// let mut bytes = s.bytes();
+    ///
+    /// assert_eq!(bytes.next(), Some(0xC5));
+    /// assert_eq!(bytes.next(), Some(b's'));
+    /// assert_eq!(bytes.next(), None);
+    /// ```
+    #[inline]
+    pub fn bytes(&self) -> Bytes<'_> {
+        Bytes::new(self)
+    }
+
+    /// Returns an iterator over the lines of a string, as string slices.
+    ///
+    /// Lines are split at line endings that are either newlines (`\n`) or sequences of a
+    /// carriage return followed by a line feed (`\r\n`). The final line ending is optional and
+    /// line terminators are not included in the returned lines.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use iso8859_1::IsoLatin1Str;
+    ///
+    /// let s = IsoLatin1Str::from_iso8859_1(b"foo\r\nb\xE4r\n\nbaz\n").unwrap();
+    /// let mut lines = s.lines();
+    ///
+    /// assert_eq!(lines.next().map(|l| l.as_bytes()), Some(&b"foo"[..]));
+    /// assert_eq!(lines.next().map(|l| l.as_bytes()), Some(&b"b\xE4r"[..]));
+    /// assert_eq!(lines.next().map(|l| l.as_bytes()), Some(&b""[..]));
+    /// assert_eq!(lines.next().map(|l| l.as_bytes()), Some(&b"baz"[..]));
+    /// assert_eq!(lines.next(), None);
+    /// ```
+    #[inline]
+    pub fn lines(&self) -> Lines<'_> {
+        Lines::new(self)
+    }
+
+    /// Splits a string slice by whitespace.
+    ///
+    /// The iterator returned will return string slices that are sub-slices of the original
+    /// string slice, separated by any amount of whitespace, as defined by
+    /// [`IsoLatin1Char::is_whitespace`]. This includes the non-breaking space (`0xA0`).
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use iso8859_1::IsoLatin1Str;
+    ///
+    /// let s = IsoLatin1Str::from_iso8859_1(b" Mary\xA0had\ta  little lamb ").unwrap();
+    /// let words: Vec<&[u8]> = s.split_whitespace().map(|w| w.as_bytes()).collect();
+    ///
+    /// assert_eq!(words, [&b"Mary"[..], b"had", b"a", b"little", b"lamb"]);
+    /// ```
+    #[inline]
+    pub fn split_whitespace(&self) -> SplitWhitespace<'_> {
+        SplitWhitespace::new(self)
+    }
+
+    /// Returns an iterator of [`u16`] over the string encoded as UTF-16.
+    ///
+    /// Every character is encoded as a single code unit with the same value as its byte.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use iso8859_1::IsoLatin1Str;
+    ///
+    /// let s = IsoLatin1Str::from_iso8859_1(b"\xC6gir").unwrap();
+    /// let utf16: Vec<u16> = s.encode_utf16().collect();
+    ///
+    /// assert_eq!(utf16, [0xC6, 0x67, 0x69, 0x72]);
+    /// ```
+    #[inline]
+    pub fn encode_utf16(&self) -> EncodeUtf16<'_> {
+        EncodeUtf16::new(self)
+    }
+
+    /// Appends the string encoded as UTF-16 to `buf`.
+    ///
+    /// This reserves the needed space once, so reusing `buf` across calls avoids allocating.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use iso8859_1::IsoLatin1Str;
+    ///
+    /// let mut buf = Vec::new();
+    /// IsoLatin1Str::from_iso8859_1(b"\xC6gir").unwrap().encode_utf16_into(&mut buf);
+    ///
+    /// assert_eq!(buf, [0xC6, 0x67, 0x69, 0x72]);
+    /// ```
+    pub fn encode_utf16_into(&self, buf: &mut Vec<u16>) {
+        buf.reserve(self.len());
+        buf.extend(self.encode_utf16());
+    }
+
+    /// Returns the lowercase equivalent of this string slice, as a new [`IsoLatin1String`].
+    ///
+    /// See [`IsoLatin1Char::to_lowercase`].
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use iso8859_1::IsoLatin1Str;
+    ///
+    /// let s = IsoLatin1Str::from_iso8859_1(b"\xC6GIR").unwrap();
+    /// assert_eq!(s.to_lowercase().as_bytes(), b"\xE6gir");
+    /// ```
+    pub fn to_lowercase(&self) -> IsoLatin1String {
+        let bytes = self.chars().map(|c| c.to_lowercase().0).collect();
+        IsoLatin1String { bytes }
+    }
+
+    /// Returns the uppercase equivalent of this string slice, as a new [`IsoLatin1String`].
+    ///
+    /// The `policy` decides what happens to the characters that do not have a single ISO8859-1
+    /// uppercase form, see [`IsoLatin1Char::to_uppercase`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] only if `policy` is [`UppercasePolicy::Error`] and a character does not
+    /// have a single ISO8859-1 uppercase form. The error holds the index of that character.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use iso8859_1::{IsoLatin1Str, UppercasePolicy};
+    ///
+    /// let s = IsoLatin1Str::from_iso8859_1(b"Stra\xDFe").unwrap();
+    ///
+    /// let upper = s.to_uppercase(UppercasePolicy::Keep).unwrap();
+    /// assert_eq!(upper.as_bytes(), b"STRA\xDFE");
+    ///
+    /// let upper = s.to_uppercase(UppercasePolicy::Substitute).unwrap();
+    /// assert_eq!(upper.as_bytes(), b"STRASSE");
+    ///
+    /// let err = s.to_uppercase(UppercasePolicy::Error).unwrap_err();
+    /// assert_eq!(err.index(), 4);
+    /// ```
+    pub fn to_uppercase(&self, policy: UppercasePolicy) -> Result<IsoLatin1String, UppercaseError> {
+        let mut bytes = Vec::with_capacity(self.len());
+        for (index, char) in self.char_indices() {
+            let upper = char
+                .to_uppercase(policy)
+                .map_err(|_| UppercaseError::new(char, index))?;
+            bytes.extend(upper.map(|c| c.0));
+        }
+
+        Ok(IsoLatin1String { bytes })
+    }
+
+    /// Returns the title case equivalent of this string slice, as a new [`IsoLatin1String`].
+    ///
+    /// The first letter of every word is converted to uppercase and the rest of the word to
+    /// lowercase. A word starts at an alphanumeric character that does not follow another
+    /// alphanumeric character or an apostrophe, so `o'neil` becomes `O'neil` and not `O'Neil`.
+    ///
+    /// The `policy` decides what happens to the first letters that do not have a single
+    /// ISO8859-1 uppercase form, see [`IsoLatin1Char::to_uppercase`]. When substituted, `ß`
+    /// becomes `Ss`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] only if `policy` is [`UppercasePolicy::Error`] and the first letter of a
+    /// word does not have a single ISO8859-1 uppercase form. The error holds the index of that
+    /// letter.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use iso8859_1::{IsoLatin1Str, UppercasePolicy};
+    ///
+    /// let s = IsoLatin1Str::from_iso8859_1(b"JOS\xC9 D'\xC1VILA-SOUSA").unwrap();
+    ///
+    /// let title = s.to_titlecase(UppercasePolicy::Keep).unwrap();
+    /// assert_eq!(title, "José D'ávila-Sousa");
+    /// ```
+    pub fn to_titlecase(&self, policy: UppercasePolicy) -> Result<IsoLatin1String, UppercaseError> {
+        let mut bytes = Vec::with_capacity(self.len());
+        let mut in_word = false;
+        for (index, char) in self.char_indices() {
+            if in_word {
+                bytes.push(char.to_lowercase().0);
+            } else {
+                push_titlecase(&mut bytes, char, index, policy)?;
+            }
+            in_word = char.is_alphanumeric() || char.0 == b'\'';
+        }
+
+        Ok(IsoLatin1String { bytes })
+    }
+
+    /// Returns a copy of this string slice with its first character converted to uppercase, as a
+    /// new [`IsoLatin1String`].
+    ///
+    /// The rest of the string is left as it is. The `policy` decides what happens if the first
+    /// character does not have a single ISO8859-1 uppercase form, see
+    /// [`IsoLatin1Char::to_uppercase`]. When substituted, `ß` becomes `Ss`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] only if `policy` is [`UppercasePolicy::Error`] and the first character
+    /// does not have a single ISO8859-1 uppercase form.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use iso8859_1::{IsoLatin1Str, UppercasePolicy};
+    ///
+    /// let s = IsoLatin1Str::from_iso8859_1(b"\xE9cole de PARIS").unwrap();
+    ///
+    /// let capitalized = s.capitalize_first(UppercasePolicy::Keep).unwrap();
+    /// assert_eq!(capitalized, "École de PARIS");
+    /// ```
+    pub fn capitalize_first(
+        &self,
+        policy: UppercasePolicy,
+    ) -> Result<IsoLatin1String, UppercaseError> {
+        let mut bytes = Vec::with_capacity(self.len());
+        if let Some((first, rest)) = self.bytes.split_first() {
+            push_titlecase(&mut bytes, IsoLatin1Char(*first), 0, policy)?;
+            bytes.extend_from_slice(rest);
+        }
+
+        Ok(IsoLatin1String { bytes })
+    }
+
+    /// Returns an object that displays this string slice in lowercase, without allocating.
+    ///
+    /// The displayed text is the same as [`to_lowercase`](IsoLatin1Str::to_lowercase) would
+    /// return.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use iso8859_1::IsoLatin1Str;
+    ///
+    /// let s = IsoLatin1Str::from_iso8859_1(b"\xC6GIR").unwrap();
+    /// assert_eq!(format!("user: {}", s.display_lowercase()), "user: ægir");
+    /// ```
+    #[inline]
+    pub fn display_lowercase(&self) -> DisplayLowercase<'_> {
+        DisplayLowercase::new(self)
+    }
+
+    /// Returns an object that displays this string slice in uppercase, without allocating.
+    ///
+    /// As the displayed text is not limited to ISO8859-1, every character is shown in its
+    /// Unicode uppercase form, like [`char::to_uppercase`] does: `ß` is shown as `SS`, `ÿ` as `Ÿ`
+    /// and `µ` as `Μ`. Use [`to_uppercase`](IsoLatin1Str::to_uppercase) to get a
+    /// [`IsoLatin1String`] instead.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use iso8859_1::IsoLatin1Str;
+    ///
+    /// let s = IsoLatin1Str::from_iso8859_1(b"Stra\xDFe").unwrap();
+    /// assert_eq!(format!("{}", s.display_uppercase()), "STRASSE");
+    /// ```
+    #[inline]
+    pub fn display_uppercase(&self) -> DisplayUppercase<'_> {
+        DisplayUppercase::new(self)
+    }
+}
+
+/// Pushes the title case form of `char` to `bytes`: its uppercase form, with every character
+/// after the first one converted back to lowercase.
+fn push_titlecase(
+    bytes: &mut Vec<u8>,
+    char: IsoLatin1Char,
+    index: usize,
+    policy: UppercasePolicy,
+) -> Result<(), UppercaseError> {
+    let mut upper = char
+        .to_uppercase(policy)
+        .map_err(|_| UppercaseError::new(char, index))?;
+    bytes.extend(upper.next().map(|c| c.0));
+    bytes.extend(upper.map(|c| c.to_lowercase().0));
+    Ok(())
+}
+
+impl AsRef<[u8]> for IsoLatin1Str {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        &self.bytes
+    }
+}
+
+impl AsRef<IsoLatin1Str> for IsoLatin1Str {
+    #[inline]
+    fn as_ref(&self) -> &IsoLatin1Str {
+        self
+    }
+}
+
+impl Default for &IsoLatin1Str {
+    #[inline]
+    fn default() -> Self {
+        // SAFETY: a empty slice is valid ISO8859-1.
+        unsafe { IsoLatin1Str::from_iso8859_1_unchecked(&[]) }
+    }
+}
+
+impl ToOwned for IsoLatin1Str {
+    type Owned = IsoLatin1String;
+
+    #[inline]
+    fn to_owned(&self) -> IsoLatin1String {
+        IsoLatin1String {
+            bytes: self.bytes.to_vec(),
+        }
+    }
+}
+
+impl fmt::Debug for IsoLatin1Str {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("\"")?;
+        fmt::Display::fmt(self, f)?;
+        f.write_str("\"")
+    }
+}
+
+impl fmt::Display for IsoLatin1Str {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for char in self.chars() {
+            fmt::Write::write_char(f, char.into())?;
+        }
+        Ok(())
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for &'a IsoLatin1Str {
+    type Error = Iso8859_1Error;
+
+    #[inline]
+    fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+        IsoLatin1Str::from_iso8859_1(bytes)
+    }
+}
+
+/// Errors which can occur when attempting to interpret a sequence of [`u8`] as a
+/// [`IsoLatin1Str`].
+///
+/// This is the borrowed counterpart of [`FromIso8859_1Error`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Iso8859_1Error {
+    valid_up_to: usize,
+    byte: u8,
+    error: IsoLatin1CharError,
+}
+
+impl Iso8859_1Error {
+    /// Returns the index in the given byte slice up to which valid ISO8859-1 was verified.
+    ///
+    /// It is the maximum index such that `IsoLatin1Str::from_iso8859_1(&bytes[..index])` would
+    /// succeed, which is also the index of the offending byte.
+    pub fn valid_up_to(&self) -> usize {
+        self.valid_up_to
+    }
+
+    /// Returns the offending byte.
+    pub fn invalid_byte(&self) -> u8 {
+        self.byte
+    }
+
+    /// Returns the [`IsoLatin1CharError`] that caused the conversion to fail.
+    pub fn char_error(&self) -> IsoLatin1CharError {
+        self.error
+    }
+}
+
+impl fmt::Display for Iso8859_1Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: byte 0x{:02X} at index {}",
+            self.error, self.byte, self.valid_up_to
+        )
+    }
+}
+
+impl std::error::Error for Iso8859_1Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// Checks that every byte of `bytes` is a valid [`IsoLatin1Char`].
+fn validate(bytes: &[u8]) -> Result<(), Iso8859_1Error> {
+    for (index, &byte) in bytes.iter().enumerate() {
+        if let Err(error) = IsoLatin1Char::try_from(byte) {
+            return Err(Iso8859_1Error {
+                valid_up_to: index,
+                byte,
+                error,
+            });
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod str_tests {
+    use super::*;
+
+    #[test]
+    fn from_iso8859_1() {
+        let s = IsoLatin1Str::from_iso8859_1(&[0x41, 0xC6, 0xFF]).unwrap();
+        assert_eq!(s.as_bytes(), &[0x41, 0xC6, 0xFF]);
+
+        let err = IsoLatin1Str::from_iso8859_1(&[0x41, 0x9F, 0x42]).unwrap_err();
+        assert_eq!(err.valid_up_to(), 1);
+        assert_eq!(err.invalid_byte(), 0x9F);
+        assert_eq!(err.char_error(), IsoLatin1CharError::Undefined);
+    }
+
+    #[test]
+    fn from_iso8859_1_const() {
+        const S: &IsoLatin1Str = IsoLatin1Str::from_iso8859_1_const(b"A\xC6\xFF");
+        assert_eq!(S.as_bytes(), b"A\xC6\xFF");
+        assert!(IsoLatin1Str::from_iso8859_1_const(b"").is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "byte undefined in ISO8859-1")]
+    fn from_iso8859_1_const_undefined() {
+        IsoLatin1Str::from_iso8859_1_const(&[0x41, 0x9F]);
+    }
+
+    #[test]
+    fn len() {
+        let s = IsoLatin1Str::from_iso8859_1(b"\xC6gir").unwrap();
+        assert_eq!(s.len(), 4);
+        assert!(!s.is_empty());
+        assert!(<&IsoLatin1Str>::default().is_empty());
+    }
+
+    #[test]
+    fn to_owned() {
+        let s = IsoLatin1Str::from_iso8859_1(b"\xC6gir").unwrap();
+        let owned = s.to_owned();
+        assert_eq!(owned.as_str(), s);
+    }
+
+    #[test]
+    fn debug() {
+        let s = IsoLatin1Str::from_iso8859_1(b"\xC6gir").unwrap();
+        assert_eq!(format!("{:?}", s), "\"Ægir\"");
+    }
+
+    #[cfg(feature = "macros")]
+    #[test]
+    fn latin1_macro() {
+        const EMPTY: &IsoLatin1Str = latin1!("");
+        assert!(EMPTY.is_empty());
+
+        assert_eq!(latin1!("Æøå").as_bytes(), b"\xC6\xF8\xE5");
+        assert_eq!(latin1!("\u{C6}\t\x41\\\"\0").as_bytes(), b"\xC6\tA\\\"\0");
+        assert_eq!(latin1!(r#"a"\n"#).as_bytes(), b"a\"\\n");
+        assert_eq!(
+            latin1!(
+                "line \
+                 continued"
+            )
+            .as_bytes(),
+            b"line continued"
+        );
+
+        macro_rules! forward {
+            ($lit:literal) => {
+                latin1!($lit)
+            };
+        }
+        assert_eq!(forward!("½").as_bytes(), b"\xBD");
+    }
+
+    #[test]
+    fn display() {
+        let s = IsoLatin1Str::from_iso8859_1(b"\xC6gir \xA9").unwrap();
+        assert_eq!(format!("{}", s), "Ægir ©");
+    }
+}
+
+/// A ISO8859-1 encoded, growable string.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use iso8859_1::IsoLatin1String;
+///
+/// let s = IsoLatin1String::from_iso8859_1(vec![0x47, 0x72, 0xFC, 0xDF, 0x65]).unwrap();
+/// assert_eq!(s.to_string(), "Grüße");
+/// ```
+///
+/// # ISO8859-1
+///
+/// Every byte of a `IsoLatin1String` is a valid [`IsoLatin1Char`]: a ISO8859-1 character or a
+/// ASCII control code. Since every character is a single byte, the length of the string in bytes
+/// is also its length in characters, and indexing a byte is indexing a character.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct IsoLatin1String {
+    bytes: Vec<u8>,
+}
+
+impl IsoLatin1String {
+    /// Creates a new empty `IsoLatin1String`.
+    ///
+    /// Given that the `IsoLatin1String` is empty, this will not allocate any initial buffer.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use iso8859_1::IsoLatin1String;
+    ///
+    /// let s = IsoLatin1String::new();
+    /// assert!(s.is_empty());
+    /// ```
+    pub const fn new() -> Self {
+        IsoLatin1String { bytes: Vec::new() }
+    }
+
+    /// Creates a new empty `IsoLatin1String` with at least the specified capacity.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use iso8859_1::IsoLatin1String;
+    ///
+    /// let s = IsoLatin1String::with_capacity(10);
+    /// assert_eq!(s.len(), 0);
+    /// assert!(s.capacity() >= 10);
+    /// ```
+    pub fn with_capacity(capacity: usize) -> Self {
+        IsoLatin1String {
+            bytes: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Converts a vector of bytes to a `IsoLatin1String`.
+    ///
+    /// Every byte is checked to be a valid [`IsoLatin1Char`], no copy of the bytes is made.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] if any byte is undefined in ISO8859-1. The returned error gives back the
+    /// vector and the position of the first invalid byte.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use iso8859_1::IsoLatin1String;
+    ///
+    /// let s = IsoLatin1String::from_iso8859_1(vec![0x47, 0x72, 0xFC, 0xDF, 0x65]).unwrap();
+    /// assert_eq!(s.len(), 5);
+    ///
+    /// let err = IsoLatin1String::from_iso8859_1(vec![0x47, 0x85]).unwrap_err();
+    /// assert_eq!(err.valid_up_to(), 1);
+    /// ```
+    pub fn from_iso8859_1(vec: Vec<u8>) -> Result<Self, FromIso8859_1Error> {
+        match validate(&vec) {
+            Ok(()) => Ok(IsoLatin1String { bytes: vec }),
+            Err(error) => Err(FromIso8859_1Error { bytes: vec, error }),
+        }
+    }
+
+    /// Converts a vector of bytes to a `IsoLatin1String` without checking that it is valid
+    /// ISO8859-1.
+    ///
+    /// # Safety
+    ///
+    /// The bytes passed in must not contain bytes in the undefined `0x80` to `0x9F` range. The
+    /// rest of the API assumes every byte of a `IsoLatin1String` is a valid [`IsoLatin1Char`].
+    pub unsafe fn from_iso8859_1_unchecked(bytes: Vec<u8>) -> Self {
+        IsoLatin1String { bytes }
+    }
+
+    /// Decodes a UTF-16 encoded slice into a `IsoLatin1String`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] if a code unit is not a ISO8859-1 character, which is the case for every
+    /// code unit above `0xFF`, surrogates included, and for the `0x80` to `0x9F` range.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use iso8859_1::IsoLatin1String;
+    ///
+    /// let utf16: Vec<u16> = "Grüße".encode_utf16().collect();
+    /// let s = IsoLatin1String::from_utf16(&utf16).unwrap();
+    /// assert_eq!(s.as_bytes(), b"Gr\xFC\xDFe");
+    ///
+    /// let utf16: Vec<u16> = "Ŋ".encode_utf16().collect();
+    /// assert!(IsoLatin1String::from_utf16(&utf16).is_err());
+    /// ```
+    pub fn from_utf16(units: &[u16]) -> Result<Self, FromUtf16Error> {
+        let bytes = utf16::units_to_bytes(units)?;
+        Ok(IsoLatin1String { bytes })
+    }
+
+    /// Converts a `IsoLatin1String` into a byte vector.
+    ///
+    /// This consumes the `IsoLatin1String`, so we do not need to copy its contents.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use iso8859_1::IsoLatin1String;
+    ///
+    /// let s = IsoLatin1String::from_iso8859_1(vec![0x41, 0x42]).unwrap();
+    /// assert_eq!(s.into_bytes(), vec![0x41, 0x42]);
+    /// ```
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.bytes
+    }
+
+    /// Extracts a string slice containing the entire `IsoLatin1String`.
+    pub fn as_str(&self) -> &IsoLatin1Str {
+        // SAFETY: `self.bytes` is always valid ISO8859-1.
+        unsafe { IsoLatin1Str::from_iso8859_1_unchecked(&self.bytes) }
+    }
+
+    /// Returns this `IsoLatin1String`'s capacity, in bytes.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use iso8859_1::IsoLatin1String;
+    ///
+    /// let s = IsoLatin1String::with_capacity(10);
+    /// assert!(s.capacity() >= 10);
+    /// ```
+    pub fn capacity(&self) -> usize {
+        self.bytes.capacity()
+    }
+
+    /// Reserves capacity for at least `additional` bytes more than the current length.
+    ///
+    /// The allocator may reserve more space to speculatively avoid frequent allocations. After
+    /// calling `reserve`, capacity will be greater than or equal to `self.len() + additional`.
+    /// Does nothing if capacity is already sufficient.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity overflows [`usize`].
+    pub fn reserve(&mut self, additional: usize) {
+        self.bytes.reserve(additional)
+    }
+
+    /// Reserves the minimum capacity for at least `additional` bytes more than the current
+    /// length.
+    ///
+    /// Unlike [`reserve`], this will not deliberately over-allocate to speculatively avoid
+    /// frequent allocations. Prefer [`reserve`] if future insertions are expected.
+    ///
+    /// [`reserve`]: IsoLatin1String::reserve
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity overflows [`usize`].
+    pub fn reserve_exact(&mut self, additional: usize) {
+        self.bytes.reserve_exact(additional)
+    }
+
+    // You guys got the idea. Try to replicate the String API into the type here.
+}
+
+impl Default for IsoLatin1String {
+    #[inline]
+    fn default() -> Self {
+        IsoLatin1String::new()
+    }
+}
+
+impl ops::Deref for IsoLatin1String {
+    type Target = IsoLatin1Str;
+
+    #[inline]
+    fn deref(&self) -> &IsoLatin1Str {
+        self.as_str()
+    }
+}
+
+impl AsRef<IsoLatin1Str> for IsoLatin1String {
+    #[inline]
+    fn as_ref(&self) -> &IsoLatin1Str {
+        self
+    }
+}
+
+impl AsRef<[u8]> for IsoLatin1String {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        &self.bytes
+    }
+}
+
+impl Borrow<IsoLatin1Str> for IsoLatin1String {
+    #[inline]
+    fn borrow(&self) -> &IsoLatin1Str {
+        self
+    }
+}
+
+impl fmt::Debug for IsoLatin1String {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl fmt::Display for IsoLatin1String {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.as_str(), f)
+    }
+}
+
+/// A possible error value when converting a `IsoLatin1String` from a ISO8859-1 byte vector.
+///
+/// This type is the error type for the [`from_iso8859_1`] method on [`IsoLatin1String`]. It is
+/// designed in such a way to carefully avoid reallocations: the [`into_bytes`] method will give
+/// back the byte vector that was used in the conversion attempt.
+///
+/// [`from_iso8859_1`]: IsoLatin1String::from_iso8859_1
+/// [`into_bytes`]: FromIso8859_1Error::into_bytes
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FromIso8859_1Error {
+    bytes: Vec<u8>,
+    error: Iso8859_1Error,
+}
+
+impl FromIso8859_1Error {
+    /// Returns a slice of the bytes that were attempted to convert to a `IsoLatin1String`.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Returns the bytes that were attempted to convert to a `IsoLatin1String`.
+    ///
+    /// This method is carefully constructed to avoid allocation. It will consume the error,
+    /// moving out the bytes, so that a copy of the bytes does not need to be made.
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.bytes
+    }
+
+    /// Fetch a [`Iso8859_1Error`] to get more details about the conversion failure.
+    pub fn iso8859_1_error(&self) -> Iso8859_1Error {
+        self.error
+    }
+
+    /// Returns the index in the given byte vector up to which valid ISO8859-1 was verified.
+    ///
+    /// It is the maximum index such that `IsoLatin1String::from_iso8859_1(bytes[..index])`
+    /// would succeed, which is also the index of the offending byte.
+    pub fn valid_up_to(&self) -> usize {
+        self.error.valid_up_to()
+    }
+
+    /// Returns the offending byte.
+    pub fn invalid_byte(&self) -> u8 {
+        self.error.invalid_byte()
+    }
+
+    /// Returns the [`IsoLatin1CharError`] that caused the conversion to fail.
+    pub fn char_error(&self) -> IsoLatin1CharError {
+        self.error.char_error()
+    }
+}
+
+impl fmt::Display for FromIso8859_1Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.error, f)
+    }
+}
+
+impl std::error::Error for FromIso8859_1Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.error.source()
+    }
 }
 
 #[cfg(test)]
@@ -603,8 +1927,11 @@ mod string_tests {
 
         // Bad case
         // Contains invalid characters
-        let res = IsoLatin1String::from_iso8859_1(vec![0x41, 0x42, 0x87, 0x44]);
-        assert!(res.is_err()); // FIXME: Ideally, we should have a more specific error type checking here.
+        let err = IsoLatin1String::from_iso8859_1(vec![0x41, 0x42, 0x87, 0x44]).unwrap_err();
+        assert_eq!(err.valid_up_to(), 2);
+        assert_eq!(err.invalid_byte(), 0x87);
+        assert_eq!(err.char_error(), IsoLatin1CharError::Undefined);
+        assert_eq!(err.into_bytes(), vec![0x41, 0x42, 0x87, 0x44]);
     }
 
     #[test]
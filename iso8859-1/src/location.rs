@@ -0,0 +1,231 @@
+//! Line and column information for decoding errors.
+//!
+//! The errors returned when decoding only know the byte index of the first invalid byte, which is
+//! not very useful to show to someone who has to fix a multi-megabyte file. A [`LocatedError`]
+//! adds the line and column of that byte and a snippet of the text around it.
+
+use std::{error::Error, fmt};
+
+use crate::{FromIso8859_1Error, Iso8859_1Error, IsoLatin1Char};
+
+/// How many bytes of context a snippet has at most on each side of the invalid byte.
+const SNIPPET_CONTEXT: usize = 24;
+
+/// A [`Iso8859_1Error`] with the line and column at which it happened.
+///
+/// This struct is created by the [`locate`] methods on [`Iso8859_1Error`] and
+/// [`FromIso8859_1Error`]. See their documentation for more.
+///
+/// Its [`Display`] implementation prints a single line message. With the alternate flag (`{:#}`),
+/// the snippet is printed below it with a marker under the invalid byte.
+///
+/// [`locate`]: Iso8859_1Error::locate
+/// [`Display`]: fmt::Display
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LocatedError {
+    error: Iso8859_1Error,
+    line: usize,
+    column: usize,
+    snippet: String,
+    marker: usize,
+}
+
+impl LocatedError {
+    fn new(error: Iso8859_1Error, bytes: &[u8]) -> Self {
+        let index = error.valid_up_to();
+        let before = &bytes[..index];
+
+        let line_start = before
+            .iter()
+            .rposition(|&byte| byte == b'\n')
+            .map_or(0, |newline| newline + 1);
+        let line = before.iter().filter(|&&byte| byte == b'\n').count() + 1;
+
+        let line_end = bytes[index..]
+            .iter()
+            .position(|&byte| byte == b'\r' || byte == b'\n')
+            .map_or(bytes.len(), |end| index + end);
+
+        let start = line_start.max(index.saturating_sub(SNIPPET_CONTEXT));
+        let end = line_end.min(index + 1 + SNIPPET_CONTEXT);
+        let snippet = bytes[start..end]
+            .iter()
+            .map(|&byte| match IsoLatin1Char::try_from(byte) {
+                Ok(char) => char::from(char),
+                Err(_) => char::REPLACEMENT_CHARACTER,
+            })
+            .collect();
+
+        LocatedError {
+            error,
+            line,
+            column: index - line_start + 1,
+            snippet,
+            marker: index - start,
+        }
+    }
+
+    /// Returns the error that was located.
+    pub fn error(&self) -> Iso8859_1Error {
+        self.error
+    }
+
+    /// Returns the line of the invalid byte, starting at 1.
+    ///
+    /// Lines are separated by `\n`, so `\r\n` line endings are counted once.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// Returns the column of the invalid byte in its line, starting at 1.
+    ///
+    /// Every ISO8859-1 character is a single byte, so this is both a byte and a character count.
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    /// Returns the text around the invalid byte, limited to its line.
+    ///
+    /// Undefined bytes, the invalid one included, are shown as `U+FFFD REPLACEMENT CHARACTER`.
+    pub fn snippet(&self) -> &str {
+        &self.snippet
+    }
+}
+
+impl fmt::Display for LocatedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid byte 0x{:02X} at line {}, column {}",
+            self.error.invalid_byte(),
+            self.line,
+            self.column
+        )?;
+
+        if f.alternate() {
+            write!(
+                f,
+                "\n  | {}\n  | {:>width$}",
+                self.snippet,
+                "^",
+                width = self.marker + 1
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Error for LocatedError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+impl Iso8859_1Error {
+    /// Finds the line and column of the error in `bytes`, the input that failed to convert.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bytes` is too short to contain the invalid byte, which happens when it is not the
+    /// input the error comes from.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use iso8859_1::IsoLatin1Str;
+    ///
+    /// let bytes = b"name;city\nJos\xE9;S\x85o Paulo\n";
+    /// let err = IsoLatin1Str::from_iso8859_1(bytes).unwrap_err().locate(bytes);
+    ///
+    /// assert_eq!(err.line(), 2);
+    /// assert_eq!(err.column(), 7);
+    /// assert_eq!(err.to_string(), "invalid byte 0x85 at line 2, column 7");
+    /// assert_eq!(
+    ///     format!("{err:#}"),
+    ///     "invalid byte 0x85 at line 2, column 7\n  | José;S\u{FFFD}o Paulo\n  |       ^"
+    /// );
+    /// ```
+    pub fn locate(&self, bytes: &[u8]) -> LocatedError {
+        assert!(
+            self.valid_up_to < bytes.len(),
+            "the error is not located in the given bytes"
+        );
+        LocatedError::new(*self, bytes)
+    }
+}
+
+impl FromIso8859_1Error {
+    /// Finds the line and column of the error in the bytes that failed to convert.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use iso8859_1::IsoLatin1String;
+    ///
+    /// let err = IsoLatin1String::from_iso8859_1(b"a\r\nb\r\n\x9F".to_vec()).unwrap_err();
+    /// let located = err.locate();
+    ///
+    /// assert_eq!((located.line(), located.column()), (3, 1));
+    /// ```
+    pub fn locate(&self) -> LocatedError {
+        LocatedError::new(self.iso8859_1_error(), self.as_bytes())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::IsoLatin1Str;
+
+    #[test]
+    fn first_line() {
+        let bytes = b"\x80abc";
+        let err = IsoLatin1Str::from_iso8859_1(bytes)
+            .unwrap_err()
+            .locate(bytes);
+        assert_eq!((err.line(), err.column()), (1, 1));
+        assert_eq!(err.snippet(), "\u{FFFD}abc");
+    }
+
+    #[test]
+    fn snippet_is_limited() {
+        let mut bytes = b"first line\n".to_vec();
+        bytes.extend(std::iter::repeat(b'a').take(100));
+        bytes.push(0x85);
+        bytes.extend(std::iter::repeat(b'b').take(100));
+        bytes.extend(b"\r\nlast line");
+
+        let err = IsoLatin1Str::from_iso8859_1(&bytes)
+            .unwrap_err()
+            .locate(&bytes);
+        assert_eq!((err.line(), err.column()), (2, 101));
+        assert_eq!(
+            err.snippet(),
+            format!("{}\u{FFFD}{}", "a".repeat(24), "b".repeat(24))
+        );
+
+        let rendered = format!("{err:#}");
+        let marker = rendered.lines().last().unwrap();
+        assert_eq!(marker, format!("  | {}^", " ".repeat(24)));
+    }
+
+    #[test]
+    fn snippet_stops_at_line_end() {
+        let bytes = b"ab\x90\r\ncd";
+        let err = IsoLatin1Str::from_iso8859_1(bytes)
+            .unwrap_err()
+            .locate(bytes);
+        assert_eq!(err.snippet(), "ab\u{FFFD}");
+    }
+
+    #[test]
+    #[should_panic]
+    fn wrong_input() {
+        let err = IsoLatin1Str::from_iso8859_1(b"abc\x85").unwrap_err();
+        err.locate(b"abc");
+    }
+}
@@ -0,0 +1,295 @@
+//! Decoding of UTF-8 with a ISO8859-1 or Windows-1252 fallback.
+//!
+//! Text of unknown origin is often mostly UTF-8 with some legacy 8-bit parts, like a mailbox or
+//! an IRC log where each message was written by a different client. Mail and IRC clients decode
+//! such text as UTF-8 and fall back to a legacy encoding for every sequence that is not valid
+//! UTF-8, which is what this module does.
+//!
+//! Both the [`decode`] function and the [`Reader`] wrapper report the byte ranges of the input that
+//! were decoded with the fallback.
+//!
+//! # Examples
+//!
+//! Basic usage:
+//!
+//! ```
+//! use iso8859_1::mixed::{self, Fallback};
+//!
+//! let (text, fallbacks) = mixed::decode(b"caf\xC3\xA9 / caf\xE9 \x93ok\x94", Fallback::Cp1252);
+//!
+//! assert_eq!(text, "café / café “ok”");
+//! assert_eq!(fallbacks, [11..12, 13..14, 16..17]);
+//! ```
+
+use std::{
+    io::{self, Read},
+    ops::Range,
+    str,
+};
+
+use crate::{cp1252, IsoLatin1Char};
+
+/// The encoding used to decode the bytes that are not valid UTF-8.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Fallback {
+    /// ISO8859-1. The bytes `0x80` to `0x9F`, undefined in ISO8859-1, are decoded as
+    /// `U+FFFD REPLACEMENT CHARACTER`.
+    Latin1,
+    /// Windows-1252, as done by [`cp1252::decode`]. This is the default, as most text labeled as
+    /// ISO8859-1 is really Windows-1252.
+    #[default]
+    Cp1252,
+}
+
+impl Fallback {
+    fn decode_byte(self, byte: u8) -> char {
+        match self {
+            Fallback::Latin1 => IsoLatin1Char::try_from(byte)
+                .map(char::from)
+                .unwrap_or(char::REPLACEMENT_CHARACTER),
+            Fallback::Cp1252 => cp1252::decode_byte(byte),
+        }
+    }
+}
+
+/// Decodes `bytes` as UTF-8, decoding every invalid sequence with `fallback`.
+///
+/// Returns the decoded text and the byte ranges of `bytes` that were decoded with the fallback.
+/// Adjacent ranges are merged.
+pub fn decode(bytes: &[u8], fallback: Fallback) -> (String, Vec<Range<usize>>) {
+    let mut text = String::with_capacity(bytes.len());
+    let mut fallbacks = Vec::new();
+    decode_into(bytes, 0, true, fallback, &mut text, &mut fallbacks);
+    (text, fallbacks)
+}
+
+/// Decodes as much of `bytes` as possible into `text`, returning the number of bytes decoded.
+///
+/// `offset` is the position of `bytes` in the whole input, used for the ranges pushed to
+/// `fallbacks`. Unless `eof` is set, an incomplete UTF-8 sequence at the end of `bytes` is left
+/// undecoded, as the rest of it may come next.
+fn decode_into(
+    bytes: &[u8],
+    offset: usize,
+    eof: bool,
+    fallback: Fallback,
+    text: &mut String,
+    fallbacks: &mut Vec<Range<usize>>,
+) -> usize {
+    let mut decoded = 0;
+    loop {
+        let rest = &bytes[decoded..];
+        let error = match str::from_utf8(rest) {
+            Ok(valid) => {
+                text.push_str(valid);
+                return bytes.len();
+            }
+            Err(error) => error,
+        };
+
+        let (valid, invalid) = rest.split_at(error.valid_up_to());
+        // SAFETY: the bytes before `valid_up_to` are valid UTF-8.
+        text.push_str(unsafe { str::from_utf8_unchecked(valid) });
+
+        let invalid_len = match error.error_len() {
+            Some(len) => len,
+            None if eof => invalid.len(),
+            None => return decoded + valid.len(),
+        };
+        text.extend(
+            invalid[..invalid_len]
+                .iter()
+                .map(|&b| fallback.decode_byte(b)),
+        );
+
+        let start = offset + decoded + valid.len();
+        let end = start + invalid_len;
+        match fallbacks.last_mut() {
+            Some(last) if last.end == start => last.end = end,
+            _ => fallbacks.push(start..end),
+        }
+
+        decoded += valid.len() + invalid_len;
+    }
+}
+
+/// A reader that decodes UTF-8 with a fallback, yielding valid UTF-8.
+///
+/// The bytes read from the inner reader are decoded like [`decode`] does, and the ranges decoded
+/// with the fallback are collected as the reading progresses.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use std::io::Read;
+///
+/// use iso8859_1::mixed::{Fallback, Reader};
+///
+/// let mut reader = Reader::new(&b"Gr\xC3\xBC\xC3\x9Fe, Gr\xFC\xDFe"[..], Fallback::Latin1);
+///
+/// let mut text = String::new();
+/// reader.read_to_string(&mut text).unwrap();
+///
+/// assert_eq!(text, "Grüße, Grüße");
+/// assert_eq!(reader.fallbacks(), [11..13]);
+/// ```
+#[derive(Debug)]
+pub struct Reader<R> {
+    inner: R,
+    fallback: Fallback,
+    /// Bytes read but not decoded yet, an incomplete UTF-8 sequence.
+    input: Vec<u8>,
+    /// The position of `input` in the inner reader.
+    offset: usize,
+    /// Decoded text not returned yet, from `output_pos` on.
+    output: String,
+    output_pos: usize,
+    fallbacks: Vec<Range<usize>>,
+    eof: bool,
+}
+
+impl<R: Read> Reader<R> {
+    /// Creates a new `Reader` decoding the bytes of `inner`.
+    pub fn new(inner: R, fallback: Fallback) -> Self {
+        Reader {
+            inner,
+            fallback,
+            input: Vec::new(),
+            offset: 0,
+            output: String::new(),
+            output_pos: 0,
+            fallbacks: Vec::new(),
+            eof: false,
+        }
+    }
+
+    /// Returns the byte ranges of the inner reader decoded with the fallback so far.
+    pub fn fallbacks(&self) -> &[Range<usize>] {
+        &self.fallbacks
+    }
+
+    /// Unwraps this `Reader`, returning the inner reader.
+    ///
+    /// Any bytes already read from the inner reader but not returned yet are lost.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    /// Reads and decodes the next chunk of the inner reader into `output`.
+    fn fill(&mut self) -> io::Result<()> {
+        let mut chunk = [0; 8 * 1024];
+
+        self.output.clear();
+        self.output_pos = 0;
+        while self.output.is_empty() && !self.eof {
+            let read = match self.inner.read(&mut chunk) {
+                Ok(read) => read,
+                Err(err) if err.kind() == io::ErrorKind::Interrupted => continue,
+                Err(err) => return Err(err),
+            };
+            self.eof = read == 0;
+            self.input.extend_from_slice(&chunk[..read]);
+
+            let decoded = decode_into(
+                &self.input,
+                self.offset,
+                self.eof,
+                self.fallback,
+                &mut self.output,
+                &mut self.fallbacks,
+            );
+            self.input.drain(..decoded);
+            self.offset += decoded;
+        }
+
+        Ok(())
+    }
+}
+
+impl<R: Read> Read for Reader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.output_pos == self.output.len() {
+            self.fill()?;
+        }
+
+        let pending = &self.output.as_bytes()[self.output_pos..];
+        let len = pending.len().min(buf.len());
+        buf[..len].copy_from_slice(&pending[..len]);
+        self.output_pos += len;
+        Ok(len)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_utf8() {
+        let (text, fallbacks) = decode("Grüße €".as_bytes(), Fallback::Latin1);
+        assert_eq!(text, "Grüße €");
+        assert!(fallbacks.is_empty());
+    }
+
+    #[test]
+    fn decode_fallbacks() {
+        let (text, fallbacks) = decode(b"\xE9t\xE9 \x80 \xC3\xA9", Fallback::Latin1);
+        assert_eq!(text, "été \u{FFFD} é");
+        assert_eq!(fallbacks, [0..1, 2..3, 4..5]);
+
+        let (text, fallbacks) = decode(b"\xE9t\xE9 \x80 \xC3\xA9", Fallback::Cp1252);
+        assert_eq!(text, "été € é");
+        assert_eq!(fallbacks, [0..1, 2..3, 4..5]);
+    }
+
+    #[test]
+    fn decode_merges_ranges() {
+        let (text, fallbacks) = decode(b"a\xFC\xDF\xE9b\xE2\x82", Fallback::Latin1);
+        assert_eq!(text, "aüßébâ\u{FFFD}");
+        assert_eq!(fallbacks, [1..4, 5..7]);
+    }
+
+    /// A reader returning one byte at a time, to split UTF-8 sequences between reads.
+    struct ByteByByte<'a>(&'a [u8]);
+
+    impl Read for ByteByByte<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            match self.0.split_first() {
+                Some((&byte, rest)) if !buf.is_empty() => {
+                    buf[0] = byte;
+                    self.0 = rest;
+                    Ok(1)
+                }
+                _ => Ok(0),
+            }
+        }
+    }
+
+    #[test]
+    fn reader_split_sequences() {
+        let bytes = b"\xE2\x82\xAC = \x80, \xC3\xA9 = \xE9\xE2\x82";
+        let mut reader = Reader::new(ByteByByte(bytes), Fallback::Cp1252);
+
+        let mut text = String::new();
+        reader.read_to_string(&mut text).unwrap();
+
+        assert_eq!(text, "€ = €, é = éâ‚");
+        assert_eq!(reader.fallbacks(), decode(bytes, Fallback::Cp1252).1);
+        assert_eq!(reader.fallbacks(), [6..7, 14..17]);
+    }
+
+    #[test]
+    fn reader_small_buffer() {
+        let mut reader = Reader::new(&b"\xFCber"[..], Fallback::Latin1);
+
+        let mut buf = [0; 1];
+        let mut bytes = Vec::new();
+        while reader.read(&mut buf).unwrap() == 1 {
+            bytes.push(buf[0]);
+        }
+
+        assert_eq!(bytes, "über".as_bytes());
+    }
+}
@@ -0,0 +1,206 @@
+//! Detection and repair of UTF-8 text decoded as ISO8859-1.
+//!
+//! When UTF-8 text is read as ISO8859-1, every non-ASCII character turns into two to four
+//! characters, like `é` into `Ã©` and `å` into `Ã¥`. The bytes of such a string are the original
+//! UTF-8 bytes, so it can be repaired by decoding them again as UTF-8.
+//!
+//! The characters whose UTF-8 encoding has a byte in the `0x80` to `0x9F` range can not be in a
+//! [`IsoLatin1Str`], so only the corruption of the other characters, like the lowercase accented
+//! letters, is found.
+//!
+//! # Examples
+//!
+//! Basic usage:
+//!
+//! ```
+//! use iso8859_1::{mojibake, IsoLatin1Str};
+//!
+//! let s = IsoLatin1Str::from_iso8859_1(b"Cr\xC3\xA8me br\xC3\xBBl\xC3\xA9e").unwrap();
+//! assert_eq!(s, "CrÃ¨me brÃ»lÃ©e");
+//!
+//! let repaired = mojibake::repair(s);
+//! assert_eq!(repaired.text(), "Crème brûlée");
+//! assert_eq!(repaired.confidence(), 1.0);
+//! ```
+
+use std::str;
+
+use crate::IsoLatin1Str;
+
+/// The confidence from which [`is_mojibake`] considers a string corrupted.
+pub const THRESHOLD: f64 = 0.5;
+
+/// The result of [`repair`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Repaired {
+    text: String,
+    confidence: f64,
+}
+
+impl Repaired {
+    /// Returns the repaired text.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Returns the repaired text, consuming `self`.
+    pub fn into_text(self) -> String {
+        self.text
+    }
+
+    /// Returns the confidence that the string was UTF-8 decoded as ISO8859-1, from `0.0` to
+    /// `1.0`.
+    ///
+    /// This is the same value as returned by [`confidence`].
+    pub fn confidence(&self) -> f64 {
+        self.confidence
+    }
+}
+
+/// Returns the confidence that `s` is UTF-8 text decoded as ISO8859-1, from `0.0` to `1.0`.
+///
+/// The confidence is the proportion of the non-ASCII bytes of `s` that form valid UTF-8
+/// sequences. A string without non-ASCII characters has a confidence of `0.0`.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use iso8859_1::{mojibake, IsoLatin1Str};
+///
+/// let s = IsoLatin1Str::from_iso8859_1(b"\xC3\xA9t\xC3\xA9").unwrap();
+/// assert_eq!(mojibake::confidence(s), 1.0);
+///
+/// let s = IsoLatin1Str::from_iso8859_1(b"\xE9t\xE9").unwrap();
+/// assert_eq!(mojibake::confidence(s), 0.0);
+/// ```
+pub fn confidence(s: &IsoLatin1Str) -> f64 {
+    let mut non_ascii = 0;
+    let mut in_sequences = 0;
+    for_each_sequence(s.as_bytes(), |bytes, char| {
+        if bytes[0] >= 0x80 {
+            non_ascii += bytes.len();
+            if char.is_some() {
+                in_sequences += bytes.len();
+            }
+        }
+    });
+
+    ratio(in_sequences, non_ascii)
+}
+
+/// Returns `true` if `s` is likely UTF-8 text decoded as ISO8859-1, that is if its
+/// [`confidence`] is at least [`THRESHOLD`].
+pub fn is_mojibake(s: &IsoLatin1Str) -> bool {
+    confidence(s) >= THRESHOLD
+}
+
+/// Repairs `s` by decoding its bytes as UTF-8.
+///
+/// Every valid UTF-8 sequence is decoded, and the other characters are kept as they are, so
+/// repairing a string that is not corrupted only changes the parts that look corrupted. Check the
+/// [confidence] of the result, or use [`is_mojibake`] first, to decide whether to keep it.
+///
+/// [confidence]: Repaired::confidence
+pub fn repair(s: &IsoLatin1Str) -> Repaired {
+    let mut text = String::with_capacity(s.len());
+    let mut non_ascii = 0;
+    let mut in_sequences = 0;
+    for_each_sequence(s.as_bytes(), |bytes, char| match char {
+        Some(char) => {
+            non_ascii += bytes.len();
+            in_sequences += bytes.len();
+            text.push(char);
+        }
+        None => {
+            if bytes[0] >= 0x80 {
+                non_ascii += 1;
+            }
+            text.push(char::from(bytes[0]));
+        }
+    });
+
+    Repaired {
+        text,
+        confidence: ratio(in_sequences, non_ascii),
+    }
+}
+
+/// Returns the proportion of the non-ASCII bytes that are in UTF-8 sequences.
+fn ratio(in_sequences: usize, non_ascii: usize) -> f64 {
+    if non_ascii == 0 {
+        0.0
+    } else {
+        in_sequences as f64 / non_ascii as f64
+    }
+}
+
+/// Calls `f` with every multi-byte UTF-8 sequence of `bytes` and its character, and with every
+/// other byte alone and no character.
+fn for_each_sequence(bytes: &[u8], mut f: impl FnMut(&[u8], Option<char>)) {
+    let mut index = 0;
+    while index < bytes.len() {
+        let chunk = &bytes[index..bytes.len().min(index + 4)];
+        let valid = match str::from_utf8(chunk) {
+            Ok(valid) => valid,
+            // SAFETY: the bytes before `valid_up_to` are valid UTF-8.
+            Err(error) => unsafe { str::from_utf8_unchecked(&chunk[..error.valid_up_to()]) },
+        };
+
+        match valid.chars().next() {
+            Some(char) if char.len_utf8() > 1 => {
+                f(&chunk[..char.len_utf8()], Some(char));
+                index += char.len_utf8();
+            }
+            _ => {
+                f(&chunk[..1], None);
+                index += 1;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn latin1(bytes: &[u8]) -> &IsoLatin1Str {
+        IsoLatin1Str::from_iso8859_1(bytes).unwrap()
+    }
+
+    #[test]
+    fn detect() {
+        assert_eq!(confidence(latin1(b"plain ascii")), 0.0);
+        assert_eq!(confidence(latin1(b"\xC3\xA5r")), 1.0);
+        assert_eq!(confidence(latin1(b"na\xEFve")), 0.0);
+        assert_eq!(confidence(latin1(b"\xC3\xA9 \xE9")), 2.0 / 3.0);
+
+        assert!(is_mojibake(latin1(b"\xC3\xA9t\xC3\xA9")));
+        assert!(!is_mojibake(latin1(b"\xC6gir \xF8l")));
+        assert!(!is_mojibake(latin1(b"")));
+    }
+
+    #[test]
+    fn repair_sequences() {
+        let repaired = repair(latin1(b"\xC2\xBFqu\xC3\xA9? \xC4\xA0 \xF0\xA0\xBE\xB4"));
+        assert_eq!(repaired.text(), "¿qué? Ġ \u{20FB4}");
+        assert_eq!(repaired.confidence(), 1.0);
+        assert_eq!(
+            repaired.confidence(),
+            confidence(latin1(b"\xC2\xBFqu\xC3\xA9? \xC4\xA0 \xF0\xA0\xBE\xB4"))
+        );
+    }
+
+    #[test]
+    fn repair_keeps_latin1() {
+        let repaired = repair(latin1(b"\xC3\xA9t\xE9 \xC3 \xC3\xC3\xA9"));
+        assert_eq!(repaired.text(), "été Ã Ãé");
+        assert_eq!(repaired.confidence(), 4.0 / 7.0);
+        assert_eq!(repaired.into_text(), "été Ã Ãé");
+
+        let repaired = repair(latin1(b"ok"));
+        assert_eq!(repaired.text(), "ok");
+        assert_eq!(repaired.confidence(), 0.0);
+    }
+}
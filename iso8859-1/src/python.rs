@@ -0,0 +1,125 @@
+//! Python interoperability through [`pyo3`].
+//!
+//! The string types are converted into Python `str` objects by decoding them. They can be
+//! extracted from a `str`, which is encoded into ISO8859-1, or from `bytes`, which are taken as
+//! ISO8859-1 code values. Either way the content is validated, and a `ValueError` is raised if it
+//! is not ISO8859-1.
+
+use pyo3::{
+    exceptions::{PyTypeError, PyValueError},
+    types::{PyBytes, PyString},
+    FromPyObject, IntoPy, PyAny, PyObject, PyResult, Python, ToPyObject,
+};
+
+use crate::{IsoLatin1Char, IsoLatin1Str, IsoLatin1String};
+
+impl ToPyObject for IsoLatin1Str {
+    #[inline]
+    fn to_object(&self, py: Python<'_>) -> PyObject {
+        self.to_string().into_py(py)
+    }
+}
+
+impl ToPyObject for IsoLatin1String {
+    #[inline]
+    fn to_object(&self, py: Python<'_>) -> PyObject {
+        self.as_str().to_object(py)
+    }
+}
+
+impl IntoPy<PyObject> for &IsoLatin1Str {
+    #[inline]
+    fn into_py(self, py: Python<'_>) -> PyObject {
+        self.to_object(py)
+    }
+}
+
+impl IntoPy<PyObject> for IsoLatin1String {
+    #[inline]
+    fn into_py(self, py: Python<'_>) -> PyObject {
+        self.as_str().to_object(py)
+    }
+}
+
+impl<'source> FromPyObject<'source> for IsoLatin1String {
+    fn extract(ob: &'source PyAny) -> PyResult<Self> {
+        if let Ok(s) = ob.downcast::<PyString>() {
+            let bytes = s
+                .to_str()?
+                .chars()
+                .enumerate()
+                .map(|(index, char)| {
+                    IsoLatin1Char::try_from(char).map(u8::from).map_err(|_| {
+                        PyValueError::new_err(format!(
+                            "character {char:?} at index {index} can not be encoded in ISO8859-1"
+                        ))
+                    })
+                })
+                .collect::<PyResult<Vec<u8>>>()?;
+            // SAFETY: every byte comes from a `IsoLatin1Char`.
+            return Ok(unsafe { IsoLatin1String::from_iso8859_1_unchecked(bytes) });
+        }
+
+        if let Ok(bytes) = ob.downcast::<PyBytes>() {
+            return IsoLatin1String::from_iso8859_1(bytes.as_bytes().to_vec())
+                .map_err(|err| PyValueError::new_err(err.to_string()));
+        }
+
+        Err(PyTypeError::new_err(format!(
+            "expected str or bytes, found {}",
+            ob.get_type().name()?
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pyo3::{types::PyBytes, IntoPy, PyObject, Python, ToPyObject};
+
+    use crate::{IsoLatin1Str, IsoLatin1String};
+
+    fn with_gil<F: FnOnce(Python<'_>)>(f: F) {
+        pyo3::prepare_freethreaded_python();
+        Python::with_gil(f)
+    }
+
+    #[test]
+    fn into_py() {
+        with_gil(|py| {
+            let s = IsoLatin1Str::from_iso8859_1(b"Gr\xFC\xDFe").unwrap();
+            let obj: PyObject = s.into_py(py);
+            assert_eq!(obj.extract::<String>(py).unwrap(), "Grüße");
+
+            let obj: PyObject = s.to_owned().into_py(py);
+            assert_eq!(obj.extract::<String>(py).unwrap(), "Grüße");
+        });
+    }
+
+    #[test]
+    fn extract_str() {
+        with_gil(|py| {
+            let s: IsoLatin1String = "Grüße".to_object(py).extract(py).unwrap();
+            assert_eq!(s.as_bytes(), b"Gr\xFC\xDFe");
+
+            let err = "Grüß €"
+                .to_object(py)
+                .extract::<IsoLatin1String>(py)
+                .unwrap_err();
+            assert!(err.to_string().contains("at index 5"), "{err}");
+        });
+    }
+
+    #[test]
+    fn extract_bytes() {
+        with_gil(|py| {
+            let bytes = PyBytes::new(py, b"Gr\xFC\xDFe");
+            let s: IsoLatin1String = bytes.extract().unwrap();
+            assert_eq!(s.as_bytes(), b"Gr\xFC\xDFe");
+
+            assert!(PyBytes::new(py, b"\x85")
+                .extract::<IsoLatin1String>()
+                .is_err());
+            assert!(42.to_object(py).extract::<IsoLatin1String>(py).is_err());
+        });
+    }
+}
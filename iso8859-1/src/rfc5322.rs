@@ -0,0 +1,206 @@
+//! Folding and unfolding of email header fields, as described in [RFC 5322].
+//!
+//! A header field is a single logical line, but long fields are folded into several physical
+//! lines to keep them under 78 characters: a line break (`CRLF`) is inserted before a whitespace
+//! character, and that whitespace starts the next line. Unfolding removes those line breaks to
+//! get the logical line back.
+//!
+//! Legacy mail was commonly written in ISO8859-1 with 8-bit transfer, so these functions work on
+//! ISO8859-1 text directly.
+//!
+//! [RFC 5322]: https://www.rfc-editor.org/rfc/rfc5322#section-2.2.3
+//!
+//! # Examples
+//!
+//! Basic usage:
+//!
+//! ```
+//! use iso8859_1::{rfc5322, IsoLatin1Str};
+//!
+//! let field = IsoLatin1Str::from_iso8859_1(
+//!     b"Subject: R\xE9union de l'\xE9quipe de d\xE9veloppement : ordre du jour, compte rendu et \
+//!       d\xE9cisions",
+//! )
+//! .unwrap();
+//!
+//! let folded = rfc5322::fold(field);
+//! assert_eq!(
+//!     folded,
+//!     "Subject: Réunion de l'équipe de développement : ordre du jour, compte rendu et\r\n décisions"
+//! );
+//!
+//! assert_eq!(rfc5322::unfold(&folded).as_str(), field);
+//! ```
+
+use crate::{IsoLatin1Str, IsoLatin1String};
+
+/// The length lines are folded at, not counting the `CRLF`.
+pub const LINE_LENGTH: usize = 78;
+
+/// Folds a header field, name included, so that its lines are at most [`LINE_LENGTH`] characters
+/// long.
+///
+/// Lines are only broken before a space or a tab that follows another character of the value, so
+/// a word longer than the limit is kept whole and its line stays longer than the limit. Line
+/// breaks already in `field` are kept, and the length count restarts after them.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use iso8859_1::{rfc5322, IsoLatin1Str};
+///
+/// let field = IsoLatin1Str::from_iso8859_1(b"To: a@example.com").unwrap();
+/// assert_eq!(rfc5322::fold(field).as_str(), field);
+/// ```
+pub fn fold(field: &IsoLatin1Str) -> IsoLatin1String {
+    let bytes = field.as_bytes();
+    let mut folded = Vec::with_capacity(bytes.len() + bytes.len() / LINE_LENGTH * 2);
+
+    // Folding before the value starts would leave a line with nothing but the field name.
+    let value_start = bytes
+        .iter()
+        .position(|&byte| byte == b':')
+        .and_then(|colon| {
+            bytes[colon + 1..]
+                .iter()
+                .position(|&byte| !is_wsp(byte))
+                .map(|offset| colon + 1 + offset)
+        })
+        .unwrap_or(0);
+
+    let mut line_start = 0;
+    let mut fold_point = None;
+    for (index, &byte) in bytes.iter().enumerate() {
+        if byte == b'\n' {
+            folded.extend_from_slice(&bytes[line_start..=index]);
+            line_start = index + 1;
+            fold_point = None;
+            continue;
+        }
+
+        if is_wsp(byte) && index > value_start && index > line_start && !is_wsp(bytes[index - 1]) {
+            fold_point = Some(index);
+        }
+
+        if index - line_start >= LINE_LENGTH {
+            if let Some(point) = fold_point.take() {
+                folded.extend_from_slice(&bytes[line_start..point]);
+                folded.extend_from_slice(b"\r\n");
+                line_start = point;
+            }
+        }
+    }
+    folded.extend_from_slice(&bytes[line_start..]);
+
+    // SAFETY: only ASCII line breaks were added to valid ISO8859-1.
+    unsafe { IsoLatin1String::from_iso8859_1_unchecked(folded) }
+}
+
+/// Unfolds a header field, removing every line break that is followed by a space or a tab.
+///
+/// Both `CRLF` and bare `LF` line breaks are removed, as received mail often has its line endings
+/// converted. The whitespace after the line break is kept.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use iso8859_1::{rfc5322, IsoLatin1Str};
+///
+/// let field = IsoLatin1Str::from_iso8859_1(b"Subject: Gr\xFC\xDFe\r\n aus\n\tK\xF6ln").unwrap();
+/// assert_eq!(rfc5322::unfold(field), "Subject: Grüße aus\tKöln");
+/// ```
+pub fn unfold(field: &IsoLatin1Str) -> IsoLatin1String {
+    let bytes = field.as_bytes();
+    let mut unfolded = Vec::with_capacity(bytes.len());
+
+    let mut index = 0;
+    while index < bytes.len() {
+        let line_break = match bytes[index..] {
+            [b'\r', b'\n', next, ..] if is_wsp(next) => 2,
+            [b'\n', next, ..] if is_wsp(next) => 1,
+            _ => 0,
+        };
+
+        if line_break == 0 {
+            unfolded.push(bytes[index]);
+            index += 1;
+        } else {
+            index += line_break;
+        }
+    }
+
+    // SAFETY: only bytes of valid ISO8859-1 were kept.
+    unsafe { IsoLatin1String::from_iso8859_1_unchecked(unfolded) }
+}
+
+/// Whether `byte` is whitespace as defined by RFC 5322 (`WSP`).
+fn is_wsp(byte: u8) -> bool {
+    byte == b' ' || byte == b'\t'
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn latin1(bytes: &[u8]) -> &IsoLatin1Str {
+        IsoLatin1Str::from_iso8859_1(bytes).unwrap()
+    }
+
+    #[test]
+    fn fold_long_field() {
+        let mut field = b"Subject:".to_vec();
+        for _ in 0..30 {
+            field.extend_from_slice(b" caf\xE9");
+        }
+
+        let folded = fold(latin1(&field));
+        let lines: Vec<&[u8]> = folded.as_bytes().split(|&byte| byte == b'\n').collect();
+        assert_eq!(lines.len(), 3);
+        for line in &lines[..2] {
+            let line = line.strip_suffix(b"\r").unwrap();
+            assert!(line.len() <= LINE_LENGTH, "{}", line.len());
+            assert!(line.len() > LINE_LENGTH - 5, "{}", line.len());
+        }
+
+        assert_eq!(unfold(&folded).as_bytes(), field);
+    }
+
+    #[test]
+    fn fold_keeps_long_words() {
+        let mut field = b"References: ".to_vec();
+        field.extend(std::iter::repeat(b'x').take(100));
+        field.extend_from_slice(b" <a@b> ");
+        field.extend(std::iter::repeat(b'y').take(100));
+
+        let folded = fold(latin1(&field));
+        let lines: Vec<&[u8]> = folded.as_bytes().split(|&byte| byte == b'\n').collect();
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[0].len(), 113);
+        assert_eq!(lines[1], b" <a@b>\r");
+        assert_eq!(lines[2].len(), 101);
+        assert!(lines[0].ends_with(b"x\r"));
+    }
+
+    #[test]
+    fn fold_not_before_value() {
+        let mut field = b"X-Long:    ".to_vec();
+        field.extend(std::iter::repeat(b'z').take(90));
+        assert_eq!(fold(latin1(&field)).as_bytes(), field);
+    }
+
+    #[test]
+    fn fold_restarts_after_line_breaks() {
+        let field = b"Subject: a\r\n b c";
+        assert_eq!(fold(latin1(field)).as_bytes(), field);
+    }
+
+    #[test]
+    fn unfold_only_folding_breaks() {
+        let field = latin1(b"A: b\r\n\r\nc\r\n\r\n d\n");
+        assert_eq!(unfold(field).as_bytes(), b"A: b\r\n\r\nc\r\n d\n");
+    }
+}
@@ -0,0 +1,254 @@
+//! Removal of control codes.
+//!
+//! Legacy data often has stray control codes in it, which break the display of the text and
+//! formats like CSV. The [`sanitize`] and [`sanitize_with`] methods of [`IsoLatin1Str`] remove or
+//! replace them, as chosen with a [`SanitizePolicy`].
+//!
+//! The control codes are the ASCII ones, `0x00` to `0x1F`, and `DEL` (`0x7F`).
+//!
+//! Terminal logs and BBS captures also have whole escape sequences in them, for colors and cursor
+//! movements, which [`strip_ansi_escapes`] removes.
+//!
+//! [`sanitize`]: IsoLatin1Str::sanitize
+//! [`sanitize_with`]: IsoLatin1Str::sanitize_with
+//! [`strip_ansi_escapes`]: IsoLatin1Str::strip_ansi_escapes
+
+use std::borrow::Cow;
+
+use crate::{IsoLatin1Char, IsoLatin1Str, IsoLatin1String};
+
+const ESC: u8 = 0x1B;
+const BEL: u8 = 0x07;
+
+/// What [`IsoLatin1Str::sanitize_with`] does with control codes.
+///
+/// The default policy removes every control code except tab, line feed and carriage return.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use iso8859_1::{IsoLatin1Char, IsoLatin1Str, SanitizePolicy};
+///
+/// let s = IsoLatin1Str::from_iso8859_1(b"a\x00b\tc\r\n").unwrap();
+///
+/// let policy = SanitizePolicy::remove().keep_whitespace(false);
+/// assert_eq!(s.sanitize_with(policy), "abc");
+///
+/// let space = IsoLatin1Char::try_from(' ').unwrap();
+/// let policy = SanitizePolicy::replace(space);
+/// assert_eq!(s.sanitize_with(policy), "a b\tc\r\n");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SanitizePolicy {
+    replacement: Option<IsoLatin1Char>,
+    keep_whitespace: bool,
+}
+
+impl SanitizePolicy {
+    /// Creates a policy that removes control codes, keeping tab, line feed and carriage return.
+    pub const fn remove() -> Self {
+        SanitizePolicy {
+            replacement: None,
+            keep_whitespace: true,
+        }
+    }
+
+    /// Creates a policy that replaces control codes with `replacement`, keeping tab, line feed
+    /// and carriage return.
+    pub const fn replace(replacement: IsoLatin1Char) -> Self {
+        SanitizePolicy {
+            replacement: Some(replacement),
+            keep_whitespace: true,
+        }
+    }
+
+    /// Sets whether tab (`\t`), line feed (`\n`) and carriage return (`\r`) are kept.
+    pub const fn keep_whitespace(mut self, keep: bool) -> Self {
+        self.keep_whitespace = keep;
+        self
+    }
+
+    /// Returns `true` if `char` is a control code affected by this policy.
+    fn applies_to(&self, char: IsoLatin1Char) -> bool {
+        match char.0 {
+            b'\t' | b'\n' | b'\r' => !self.keep_whitespace,
+            0x00..=0x1F | 0x7F => true,
+            _ => false,
+        }
+    }
+}
+
+impl Default for SanitizePolicy {
+    #[inline]
+    fn default() -> Self {
+        SanitizePolicy::remove()
+    }
+}
+
+impl IsoLatin1Str {
+    /// Returns a copy of this string slice without control codes, except tab, line feed and
+    /// carriage return.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use iso8859_1::IsoLatin1Str;
+    ///
+    /// let s = IsoLatin1Str::from_iso8859_1(b"\x1B[1mCaf\xE9\x7F\x00;10\r\n").unwrap();
+    /// assert_eq!(s.sanitize(), "[1mCafé;10\r\n");
+    /// ```
+    pub fn sanitize(&self) -> IsoLatin1String {
+        self.sanitize_with(SanitizePolicy::default())
+    }
+
+    /// Returns a copy of this string slice with its control codes removed or replaced according
+    /// to `policy`.
+    ///
+    /// See [`SanitizePolicy`] for an example.
+    pub fn sanitize_with(&self, policy: SanitizePolicy) -> IsoLatin1String {
+        let mut bytes = Vec::with_capacity(self.len());
+        for char in self.chars() {
+            if !policy.applies_to(char) {
+                bytes.push(char.0);
+            } else if let Some(replacement) = policy.replacement {
+                bytes.push(replacement.0);
+            }
+        }
+
+        // SAFETY: every byte comes from a `IsoLatin1Char`.
+        unsafe { IsoLatin1String::from_iso8859_1_unchecked(bytes) }
+    }
+
+    /// Returns this string slice without its ANSI escape sequences.
+    ///
+    /// The removed sequences are the control sequences (`ESC [`, like the colors `ESC [ 1 ; 31 m`),
+    /// the operating system commands (`ESC ]`, like window titles), ended by `BEL` or `ESC \`,
+    /// and the other escape sequences (`ESC` followed by a single character, like `ESC 7`). A
+    /// sequence that is not complete is removed up to where it stops being valid.
+    ///
+    /// The string slice is borrowed if it has no escape sequences.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use std::borrow::Cow;
+    ///
+    /// use iso8859_1::IsoLatin1Str;
+    ///
+    /// let s = IsoLatin1Str::from_iso8859_1(b"\x1B[1;33mAten\xE7\xE3o\x1B[0m: disco cheio").unwrap();
+    /// assert_eq!(&*s.strip_ansi_escapes(), "Atenção: disco cheio");
+    ///
+    /// let s = IsoLatin1Str::from_iso8859_1(b"sem cores").unwrap();
+    /// assert!(matches!(s.strip_ansi_escapes(), Cow::Borrowed(_)));
+    /// ```
+    pub fn strip_ansi_escapes(&self) -> Cow<'_, IsoLatin1Str> {
+        let bytes = self.as_bytes();
+        let mut index = match bytes.iter().position(|&byte| byte == ESC) {
+            Some(index) => index,
+            None => return Cow::Borrowed(self),
+        };
+
+        let mut stripped = Vec::with_capacity(bytes.len());
+        stripped.extend_from_slice(&bytes[..index]);
+        while index < bytes.len() {
+            if bytes[index] == ESC {
+                index += escape_len(&bytes[index..]);
+            } else {
+                stripped.push(bytes[index]);
+                index += 1;
+            }
+        }
+
+        // SAFETY: the bytes come from a `IsoLatin1Str`.
+        Cow::Owned(unsafe { IsoLatin1String::from_iso8859_1_unchecked(stripped) })
+    }
+}
+
+/// Returns the length of the escape sequence at the start of `bytes`, which starts with `ESC`.
+fn escape_len(bytes: &[u8]) -> usize {
+    let len_while = |start: usize, f: fn(u8) -> bool| {
+        start + bytes[start..].iter().take_while(|&&byte| f(byte)).count()
+    };
+    let len_with_final = |end: usize, f: fn(u8) -> bool| match bytes.get(end) {
+        Some(&byte) if f(byte) => end + 1,
+        _ => end,
+    };
+
+    match bytes.get(1) {
+        Some(b'[') => {
+            let end = len_while(2, |byte| matches!(byte, 0x20..=0x3F));
+            len_with_final(end, |byte| matches!(byte, 0x40..=0x7E))
+        }
+        Some(b']') => {
+            let mut end = 2;
+            while end < bytes.len() {
+                match bytes[end] {
+                    BEL => return end + 1,
+                    ESC if bytes.get(end + 1) == Some(&b'\\') => return end + 2,
+                    _ => end += 1,
+                }
+            }
+            end
+        }
+        Some(_) => {
+            let end = len_while(1, |byte| matches!(byte, 0x20..=0x2F));
+            len_with_final(end, |byte| matches!(byte, 0x30..=0x7E))
+        }
+        None => 1,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn latin1(bytes: &[u8]) -> &IsoLatin1Str {
+        IsoLatin1Str::from_iso8859_1(bytes).unwrap()
+    }
+
+    #[test]
+    fn sanitize_default() {
+        let s = latin1(b"\x00\x01a\x08b\x0B\x0C\x1F\x7F\t\n\r\xA0\xFF");
+        assert_eq!(s.sanitize().as_bytes(), b"ab\t\n\r\xA0\xFF");
+    }
+
+    #[test]
+    fn sanitize_all() {
+        let s = latin1(b"a\tb\nc\x00");
+        let policy = SanitizePolicy::remove().keep_whitespace(false);
+        assert_eq!(s.sanitize_with(policy).as_bytes(), b"abc");
+
+        let policy = SanitizePolicy::replace(IsoLatin1Char(b'?')).keep_whitespace(false);
+        assert_eq!(s.sanitize_with(policy).as_bytes(), b"a?b?c?");
+    }
+
+    #[test]
+    fn strip_ansi_escapes() {
+        let s = latin1(b"\x1B[31;1mred\x1B[m \x1B[2J\x1B[?25lclear");
+        assert_eq!(&*s.strip_ansi_escapes(), "red clear");
+
+        let s = latin1(b"\x1B]0;t\xEDtulo\x07a\x1B]2;x\x1B\\b\x1B7c\x1B(Bd");
+        assert_eq!(&*s.strip_ansi_escapes(), "abcd");
+
+        let s = latin1(b"\x1B[1\xE9t\xE9\x1B]unterminated");
+        assert_eq!(s.strip_ansi_escapes().as_bytes(), b"\xE9t\xE9");
+
+        assert_eq!(&*latin1(b"end\x1B").strip_ansi_escapes(), "end");
+        assert!(matches!(
+            latin1(b"plain\x07").strip_ansi_escapes(),
+            Cow::Borrowed(_)
+        ));
+    }
+
+    #[test]
+    fn sanitize_string() {
+        let s = IsoLatin1String::from_iso8859_1(b"\x07ding".to_vec()).unwrap();
+        assert_eq!(s.sanitize().as_bytes(), b"ding");
+    }
+}
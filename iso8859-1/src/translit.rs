@@ -0,0 +1,297 @@
+//! Transliteration of characters that are not in ISO8859-1.
+//!
+//! The lossy encoding methods of [`IsoLatin1String`] replace the characters that can't be encoded
+//! with a ISO8859-1 approximation, looked up in a [`Transliteration`].
+//!
+//! The text can also be composed before encoding, so combining sequences like `A` followed by
+//! `U+030A COMBINING RING ABOVE` are encoded as their precomposed ISO8859-1 character, `Å`.
+
+use std::{borrow::Cow, collections::HashMap};
+
+use crate::{compose::Composed, IsoLatin1Char, IsoLatin1Str, IsoLatin1String};
+
+/// The rules used to replace characters that are not in ISO8859-1 when encoding.
+///
+/// A character is looked up in the rules added with [`rule`], then in the built-in rules, which
+/// cover common typographic punctuation and the letters of Windows-1252 that are missing from
+/// ISO8859-1 (`€` becomes `EUR`, `“` becomes `"`, `–` becomes `-`, `œ` becomes `oe`, ...). A
+/// character without a rule is replaced with the [`replacement`], `?` by default.
+///
+/// [`rule`]: Transliteration::rule
+/// [`replacement`]: Transliteration::replacement
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use iso8859_1::{IsoLatin1Str, IsoLatin1String, Transliteration};
+///
+/// let s = IsoLatin1String::from_str_lossy("“Crème brûlée” – 5 €");
+/// assert_eq!(s.as_bytes(), b"\"Cr\xE8me br\xFBl\xE9e\" - 5 EUR");
+///
+/// let rules = Transliteration::new()
+///     .rule('€', IsoLatin1Str::from_iso8859_1(b"euros").unwrap())
+///     .replacement(IsoLatin1Str::from_iso8859_1(b"").unwrap());
+/// let s = IsoLatin1String::from_str_with("5 € ☺", &rules);
+/// assert_eq!(s.as_bytes(), b"5 euros ");
+/// ```
+#[derive(Debug, Clone)]
+pub struct Transliteration {
+    rules: HashMap<char, IsoLatin1String>,
+    builtin: bool,
+    replacement: Cow<'static, IsoLatin1Str>,
+    compose: bool,
+}
+
+impl Transliteration {
+    /// Creates a `Transliteration` with the built-in rules.
+    pub fn new() -> Self {
+        Transliteration {
+            rules: HashMap::new(),
+            builtin: true,
+            replacement: Cow::Borrowed(ascii(b"?")),
+            compose: false,
+        }
+    }
+
+    /// Creates a `Transliteration` without the built-in rules, replacing every character that is
+    /// not in ISO8859-1 unless a rule is added for it.
+    pub fn empty() -> Self {
+        Transliteration {
+            builtin: false,
+            ..Transliteration::new()
+        }
+    }
+
+    /// Adds a rule replacing `char` with `replacement`, taking precedence over the built-in rule
+    /// for the same character.
+    ///
+    /// Rules for ISO8859-1 characters are never used, as those are encoded as they are.
+    pub fn rule(mut self, char: char, replacement: &IsoLatin1Str) -> Self {
+        self.rules.insert(char, replacement.to_owned());
+        self
+    }
+
+    /// Sets the replacement of the characters that have no rule, which can be empty to drop them.
+    pub fn replacement(mut self, replacement: &IsoLatin1Str) -> Self {
+        self.replacement = Cow::Owned(replacement.to_owned());
+        self
+    }
+
+    /// Sets whether the text is composed before encoding, which is disabled by default.
+    ///
+    /// Decomposed text, as produced by some file systems and input methods, has letters with
+    /// diacritics written as a base letter followed by combining marks. Composing it first encodes
+    /// those letters as the ISO8859-1 character instead of transliterating the marks. Only the
+    /// sequences that are canonically equivalent to a ISO8859-1 character are composed, which is
+    /// what Unicode normalization form C (NFC) does for them.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use iso8859_1::{IsoLatin1String, Transliteration};
+    ///
+    /// let decomposed = "A\u{30A}ngstro\u{308}m";
+    ///
+    /// let s = IsoLatin1String::from_str_with(decomposed, &Transliteration::new());
+    /// assert_eq!(s.as_bytes(), b"A?ngstro?m");
+    ///
+    /// let s = IsoLatin1String::from_str_with(decomposed, &Transliteration::new().compose(true));
+    /// assert_eq!(s.as_bytes(), b"\xC5ngstr\xF6m");
+    /// ```
+    pub fn compose(mut self, compose: bool) -> Self {
+        self.compose = compose;
+        self
+    }
+
+    /// Returns the ISO8859-1 text `char` is replaced with when it can't be encoded.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use iso8859_1::Transliteration;
+    ///
+    /// let rules = Transliteration::new();
+    /// assert_eq!(rules.transliterate('œ').as_bytes(), b"oe");
+    /// assert_eq!(rules.transliterate('☺').as_bytes(), b"?");
+    /// ```
+    pub fn transliterate(&self, char: char) -> &IsoLatin1Str {
+        if let Some(replacement) = self.rules.get(&char) {
+            return replacement;
+        }
+
+        match builtin(char) {
+            Some(replacement) if self.builtin => ascii(replacement),
+            _ => &self.replacement,
+        }
+    }
+}
+
+impl Default for Transliteration {
+    /// Creates a `Transliteration` with the built-in rules.
+    #[inline]
+    fn default() -> Self {
+        Transliteration::new()
+    }
+}
+
+impl IsoLatin1String {
+    /// Encodes a UTF-8 string into a `IsoLatin1String`, replacing the characters that are not in
+    /// ISO8859-1 with the built-in [`Transliteration`] rules.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use iso8859_1::IsoLatin1String;
+    ///
+    /// let s = IsoLatin1String::from_str_lossy("Œuvre — ½ €");
+    /// assert_eq!(s.as_bytes(), b"OEuvre - \xBD EUR");
+    /// ```
+    pub fn from_str_lossy(s: &str) -> Self {
+        IsoLatin1String::from_str_with(s, &Transliteration::new())
+    }
+
+    /// Encodes a UTF-8 string into a `IsoLatin1String`, replacing the characters that are not in
+    /// ISO8859-1 according to `rules`.
+    ///
+    /// See [`Transliteration`] for an example.
+    pub fn from_str_with(s: &str, rules: &Transliteration) -> Self {
+        if rules.compose {
+            return encode_with(Composed::new(s).map(|(_, char)| char), s.len(), rules);
+        }
+
+        encode_with(s.chars(), s.len(), rules)
+    }
+}
+
+/// Encodes `chars`, replacing the characters that are not in ISO8859-1 according to `rules`.
+fn encode_with<I>(chars: I, capacity: usize, rules: &Transliteration) -> IsoLatin1String
+where
+    I: Iterator<Item = char>,
+{
+    let mut bytes = Vec::with_capacity(capacity);
+    for char in chars {
+        match IsoLatin1Char::try_from(char) {
+            Ok(char) => bytes.push(u8::from(char)),
+            Err(_) => bytes.extend_from_slice(rules.transliterate(char).as_bytes()),
+        }
+    }
+
+    // SAFETY: every byte comes from a `IsoLatin1Char` or a `IsoLatin1Str`.
+    unsafe { IsoLatin1String::from_iso8859_1_unchecked(bytes) }
+}
+
+/// Converts an ASCII replacement of the built-in rules.
+fn ascii(bytes: &'static [u8]) -> &'static IsoLatin1Str {
+    debug_assert!(bytes.is_ascii());
+    // SAFETY: ASCII bytes are all valid ISO8859-1.
+    unsafe { IsoLatin1Str::from_iso8859_1_unchecked(bytes) }
+}
+
+/// Returns the built-in replacement of `char`, if any.
+fn builtin(char: char) -> Option<&'static [u8]> {
+    let replacement: &[u8] = match char {
+        // Windows-1252 letters missing from ISO8859-1
+        'Œ' => b"OE",
+        'œ' => b"oe",
+        'Š' => b"S",
+        'š' => b"s",
+        'Ž' => b"Z",
+        'ž' => b"z",
+        'Ÿ' => b"Y",
+        'ƒ' => b"f",
+        'Ł' => b"L",
+        'ł' => b"l",
+        'ı' => b"i",
+        'ﬁ' => b"fi",
+        'ﬂ' => b"fl",
+        // quotes
+        '‘' | '’' | '‚' | '‛' | '′' => b"'",
+        '“' | '”' | '„' | '‟' | '″' => b"\"",
+        '‹' => b"<",
+        '›' => b">",
+        // dashes
+        '‐' | '‑' | '‒' | '–' | '—' | '―' | '−' => b"-",
+        // spaces
+        '\u{2002}'..='\u{200A}' | '\u{202F}' | '\u{205F}' => b" ",
+        '\u{200B}' | '\u{2060}' | '\u{FEFF}' => b"",
+        // other punctuation and symbols
+        '…' => b"...",
+        '•' => b"*",
+        '†' => b"+",
+        '‰' => b"%o",
+        'ˆ' => b"^",
+        '˜' => b"~",
+        '€' => b"EUR",
+        '™' => b"TM",
+        '←' => b"<-",
+        '→' => b"->",
+        '≤' => b"<=",
+        '≥' => b">=",
+        '≠' => b"!=",
+        _ => return None,
+    };
+
+    Some(replacement)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn latin1(bytes: &[u8]) -> &IsoLatin1Str {
+        IsoLatin1Str::from_iso8859_1(bytes).unwrap()
+    }
+
+    #[test]
+    fn builtin_rules() {
+        let s = IsoLatin1String::from_str_lossy("„Grüße“ … ‘x’ – œ € ™\u{200B}!");
+        assert_eq!(s.as_bytes(), b"\"Gr\xFC\xDFe\" ... 'x' - oe EUR TM!");
+    }
+
+    #[test]
+    fn latin1_is_kept() {
+        let text = "«Ærø» ½ ÿ ß";
+        let s = IsoLatin1String::from_str_lossy(text);
+        assert_eq!(s, text);
+    }
+
+    #[test]
+    fn custom_rules() {
+        let rules = Transliteration::new()
+            .rule('€', latin1(b"\xA4"))
+            .rule('☺', latin1(b":)"))
+            .rule('é', latin1(b"e"));
+        let s = IsoLatin1String::from_str_with("é 3€ ☺ ☹", &rules);
+        assert_eq!(s.as_bytes(), b"\xE9 3\xA4 :) ?");
+    }
+
+    #[test]
+    fn empty_rules() {
+        let rules = Transliteration::empty().replacement(latin1(b"_"));
+        assert_eq!(rules.transliterate('€'), "_");
+
+        let rules = rules.rule('€', latin1(b"E"));
+        let s = IsoLatin1String::from_str_with("1€ — 2", &rules);
+        assert_eq!(s.as_bytes(), b"1E _ 2");
+    }
+
+    #[test]
+    fn compose() {
+        let rules = Transliteration::new().compose(true);
+
+        let s = IsoLatin1String::from_str_with("Jose\u{301} Mu\u{308}ller", &rules);
+        assert_eq!(s, "José Müller");
+
+        // A mark that doesn't compose into ISO8859-1 is replaced on its own.
+        let s = IsoLatin1String::from_str_with("Erdo\u{30B}s \u{212B}", &rules);
+        assert_eq!(s.as_bytes(), b"Erdo?s \xC5");
+    }
+}
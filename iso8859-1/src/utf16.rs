@@ -0,0 +1,208 @@
+//! UTF-16 interoperability.
+//!
+//! ISO8859-1 code values are the same as the first 256 Unicode code points, so every
+//! [`IsoLatin1Char`] is a single UTF-16 code unit with the same value. This allows converting
+//! from and into UTF-16 without going through UTF-8.
+//!
+//! With the `widestring` feature enabled, conversions with the [`widestring`] string types are
+//! also available.
+
+use std::{error::Error, fmt, iter::FusedIterator};
+
+use crate::{IsoLatin1Char, IsoLatin1Str};
+
+/// An iterator of [`u16`] over the string encoded as UTF-16.
+///
+/// This struct is created by the [`encode_utf16`] method on [`IsoLatin1Str`]. See its
+/// documentation for more.
+///
+/// [`encode_utf16`]: IsoLatin1Str::encode_utf16
+#[derive(Clone, Debug)]
+pub struct EncodeUtf16<'a> {
+    iter: crate::Bytes<'a>,
+}
+
+impl<'a> EncodeUtf16<'a> {
+    pub(crate) fn new(s: &'a IsoLatin1Str) -> Self {
+        EncodeUtf16 { iter: s.bytes() }
+    }
+}
+
+impl Iterator for EncodeUtf16<'_> {
+    type Item = u16;
+
+    #[inline]
+    fn next(&mut self) -> Option<u16> {
+        self.iter.next().map(u16::from)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl DoubleEndedIterator for EncodeUtf16<'_> {
+    #[inline]
+    fn next_back(&mut self) -> Option<u16> {
+        self.iter.next_back().map(u16::from)
+    }
+}
+
+impl ExactSizeIterator for EncodeUtf16<'_> {}
+
+impl FusedIterator for EncodeUtf16<'_> {}
+
+/// A possible error value when converting a `IsoLatin1String` from a UTF-16 slice.
+///
+/// This type is the error type for the [`from_utf16`] method on [`IsoLatin1String`].
+///
+/// [`from_utf16`]: crate::IsoLatin1String::from_utf16
+/// [`IsoLatin1String`]: crate::IsoLatin1String
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FromUtf16Error {
+    valid_up_to: usize,
+    unit: u16,
+}
+
+impl FromUtf16Error {
+    /// Returns the index in the given slice up to which the code units could be converted.
+    pub fn valid_up_to(&self) -> usize {
+        self.valid_up_to
+    }
+
+    /// Returns the code unit that could not be converted.
+    pub fn invalid_unit(&self) -> u16 {
+        self.unit
+    }
+}
+
+impl fmt::Display for FromUtf16Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "UTF-16 code unit 0x{:04X} at index {} is not a ISO8859-1 character",
+            self.unit, self.valid_up_to
+        )
+    }
+}
+
+impl Error for FromUtf16Error {}
+
+/// Converts every UTF-16 code unit of `units` into a byte, failing on the first code unit that is
+/// not a [`IsoLatin1Char`].
+///
+/// Surrogates always fail, as they are never ISO8859-1 characters.
+pub(crate) fn units_to_bytes(units: &[u16]) -> Result<Vec<u8>, FromUtf16Error> {
+    units
+        .iter()
+        .enumerate()
+        .map(|(index, &unit)| {
+            u8::try_from(unit)
+                .ok()
+                .and_then(|byte| IsoLatin1Char::try_from(byte).ok())
+                .map(u8::from)
+                .ok_or(FromUtf16Error {
+                    valid_up_to: index,
+                    unit,
+                })
+        })
+        .collect()
+}
+
+#[cfg(feature = "widestring")]
+mod widestring_impls {
+    use widestring::{U16Str, U16String};
+
+    use super::FromUtf16Error;
+    use crate::{IsoLatin1Str, IsoLatin1String};
+
+    impl From<&IsoLatin1Str> for U16String {
+        #[inline]
+        fn from(s: &IsoLatin1Str) -> Self {
+            U16String::from_vec(s.encode_utf16().collect::<Vec<_>>())
+        }
+    }
+
+    impl From<IsoLatin1String> for U16String {
+        #[inline]
+        fn from(s: IsoLatin1String) -> Self {
+            U16String::from(s.as_str())
+        }
+    }
+
+    impl TryFrom<&U16Str> for IsoLatin1String {
+        type Error = FromUtf16Error;
+
+        #[inline]
+        fn try_from(s: &U16Str) -> Result<Self, Self::Error> {
+            IsoLatin1String::from_utf16(s.as_slice())
+        }
+    }
+
+    impl TryFrom<U16String> for IsoLatin1String {
+        type Error = FromUtf16Error;
+
+        #[inline]
+        fn try_from(s: U16String) -> Result<Self, Self::Error> {
+            IsoLatin1String::from_utf16(s.as_slice())
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn to_u16_string() {
+            let s = IsoLatin1Str::from_iso8859_1(b"Gr\xFC\xDFe").unwrap();
+            assert_eq!(U16String::from(s), U16String::from_str("Grüße"));
+        }
+
+        #[test]
+        fn from_u16_string() {
+            let wide = U16String::from_str("Grüße");
+            let s = IsoLatin1String::try_from(wide).unwrap();
+            assert_eq!(s.as_bytes(), b"Gr\xFC\xDFe");
+
+            let wide = U16String::from_str("Gruß €");
+            let err = IsoLatin1String::try_from(wide.as_ustr()).unwrap_err();
+            assert_eq!(err.valid_up_to(), 5);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{IsoLatin1Str, IsoLatin1String};
+
+    #[test]
+    fn encode_utf16() {
+        let s = IsoLatin1Str::from_iso8859_1(b"Gr\xFC\xDFe \xBD").unwrap();
+        let units: Vec<u16> = s.encode_utf16().collect();
+        assert_eq!(units, "Grüße ½".encode_utf16().collect::<Vec<_>>());
+
+        let mut buf = vec![0x41];
+        s.encode_utf16_into(&mut buf);
+        assert_eq!(buf.len(), 8);
+        assert_eq!(buf[1..], units);
+    }
+
+    #[test]
+    fn from_utf16() {
+        let units: Vec<u16> = "Grüße ½".encode_utf16().collect();
+        let s = IsoLatin1String::from_utf16(&units).unwrap();
+        assert_eq!(s.as_bytes(), b"Gr\xFC\xDFe \xBD");
+
+        // C1 control codes are not ISO8859-1 characters
+        let err = IsoLatin1String::from_utf16(&[0x41, 0x85]).unwrap_err();
+        assert_eq!(err.valid_up_to(), 1);
+        assert_eq!(err.invalid_unit(), 0x85);
+
+        // neither are surrogates
+        let units: Vec<u16> = "a😀".encode_utf16().collect();
+        let err = IsoLatin1String::from_utf16(&units).unwrap_err();
+        assert_eq!(err.valid_up_to(), 1);
+        assert_eq!(err.invalid_unit(), 0xD83D);
+    }
+}
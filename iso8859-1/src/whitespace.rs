@@ -0,0 +1,109 @@
+//! Normalization of whitespace.
+//!
+//! OCR output and fixed-width legacy records are padded and split with all kinds of whitespace,
+//! no-break spaces (`0xA0`) included. The [`normalize_whitespace`] method turns them back into
+//! single spaces between words.
+//!
+//! [`normalize_whitespace`]: IsoLatin1Str::normalize_whitespace
+
+use std::borrow::Cow;
+
+use crate::{IsoLatin1Char, IsoLatin1Str, IsoLatin1String};
+
+impl IsoLatin1Str {
+    /// Returns this string slice with its runs of whitespace collapsed into single spaces, and
+    /// without leading and trailing whitespace.
+    ///
+    /// Whitespace is as in [`IsoLatin1Char::is_whitespace`]: the ASCII whitespace and the
+    /// no-break space (`0xA0`).
+    ///
+    /// The string slice is borrowed if it is already normalized.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use std::borrow::Cow;
+    ///
+    /// use iso8859_1::IsoLatin1Str;
+    ///
+    /// let s = IsoLatin1Str::from_iso8859_1(b"  Jos\xE9\xA0\xA0da   Silva\t\r\n").unwrap();
+    /// assert_eq!(&*s.normalize_whitespace(), "José da Silva");
+    ///
+    /// let s = IsoLatin1Str::from_iso8859_1(b"Jos\xE9 da Silva").unwrap();
+    /// assert!(matches!(s.normalize_whitespace(), Cow::Borrowed(_)));
+    /// ```
+    pub fn normalize_whitespace(&self) -> Cow<'_, IsoLatin1Str> {
+        if is_normalized(self.as_bytes()) {
+            return Cow::Borrowed(self);
+        }
+
+        let mut bytes = Vec::with_capacity(self.len());
+        for word in self.split_whitespace() {
+            if !bytes.is_empty() {
+                bytes.push(b' ');
+            }
+            bytes.extend_from_slice(word.as_bytes());
+        }
+
+        // SAFETY: the bytes come from a `IsoLatin1Str`, and spaces are valid.
+        Cow::Owned(unsafe { IsoLatin1String::from_iso8859_1_unchecked(bytes) })
+    }
+}
+
+/// Returns `true` if the only whitespace in `bytes` is single spaces between other characters.
+fn is_normalized(bytes: &[u8]) -> bool {
+    let is_whitespace = |byte: &u8| IsoLatin1Char(*byte).is_whitespace();
+
+    let at_end =
+        bytes.first().map_or(false, is_whitespace) || bytes.last().map_or(false, is_whitespace);
+    let only_spaces = bytes
+        .iter()
+        .all(|byte| *byte == b' ' || !is_whitespace(byte));
+    !at_end && only_spaces && !bytes.windows(2).any(|pair| pair == b"  ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn latin1(bytes: &[u8]) -> &IsoLatin1Str {
+        IsoLatin1Str::from_iso8859_1(bytes).unwrap()
+    }
+
+    #[test]
+    fn collapse() {
+        let s = latin1(b"a \t b\n\nc\xA0d \xA0 e\x0B\x0Cf");
+        assert_eq!(&*s.normalize_whitespace(), "a b c d e f");
+    }
+
+    #[test]
+    fn trim() {
+        assert_eq!(&*latin1(b" \xA0x\r\n").normalize_whitespace(), "x");
+        assert_eq!(&*latin1(b" \t\xA0\n").normalize_whitespace(), "");
+        assert_eq!(&*latin1(b"\xA0").normalize_whitespace(), "");
+    }
+
+    #[test]
+    fn borrowed() {
+        for bytes in [&b""[..], b"x", b"a b", b"a\xADb c\x00d"] {
+            assert!(
+                matches!(latin1(bytes).normalize_whitespace(), Cow::Borrowed(_)),
+                "{bytes:?}"
+            );
+        }
+        for bytes in [&b" "[..], b"a\tb", b"a  b", b"a\xA0b", b"x "] {
+            assert!(
+                matches!(latin1(bytes).normalize_whitespace(), Cow::Owned(_)),
+                "{bytes:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn string() {
+        let s = IsoLatin1String::from_iso8859_1(b"\xC0  bient\xF4t ".to_vec()).unwrap();
+        assert_eq!(s.normalize_whitespace().as_bytes(), b"\xC0 bient\xF4t");
+    }
+}
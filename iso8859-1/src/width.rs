@@ -0,0 +1,103 @@
+//! Display width and soft hyphens.
+//!
+//! Every ISO8859-1 character takes a single column, except the control codes, which are not
+//! printed, and the soft hyphen (`0xAD`), which only shows up when a line is broken at it. The
+//! [`display_width`] method counts columns accordingly, and [`strip_soft_hyphens`] removes the
+//! soft hyphens once they are not needed.
+//!
+//! [`display_width`]: IsoLatin1Str::display_width
+//! [`strip_soft_hyphens`]: IsoLatin1Str::strip_soft_hyphens
+
+use std::borrow::Cow;
+
+use crate::{IsoLatin1Str, IsoLatin1String};
+
+const SOFT_HYPHEN: u8 = 0xAD;
+
+impl IsoLatin1Str {
+    /// Returns the number of columns this string slice takes when printed.
+    ///
+    /// Control codes (`0x00` to `0x1F` and `DEL`) and the soft hyphen (`0xAD`) take no column,
+    /// every other character takes one. A no-break space (`0xA0`) takes a column, like a space.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use iso8859_1::IsoLatin1Str;
+    ///
+    /// let s = IsoLatin1Str::from_iso8859_1(b"Ge\xADw\xE4sser\r\n").unwrap();
+    /// assert_eq!(s.len(), 11);
+    /// assert_eq!(s.display_width(), 8);
+    /// ```
+    pub fn display_width(&self) -> usize {
+        self.as_bytes()
+            .iter()
+            .filter(|&&byte| !matches!(byte, 0x00..=0x1F | 0x7F | SOFT_HYPHEN))
+            .count()
+    }
+
+    /// Returns this string slice without its soft hyphens (`0xAD`).
+    ///
+    /// The string slice is borrowed if it has no soft hyphens.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use std::borrow::Cow;
+    ///
+    /// use iso8859_1::IsoLatin1Str;
+    ///
+    /// let s = IsoLatin1Str::from_iso8859_1(b"Ge\xADw\xE4s\xADser").unwrap();
+    /// assert_eq!(&*s.strip_soft_hyphens(), "Gewässer");
+    ///
+    /// let s = IsoLatin1Str::from_iso8859_1(b"Wasser").unwrap();
+    /// assert!(matches!(s.strip_soft_hyphens(), Cow::Borrowed(_)));
+    /// ```
+    pub fn strip_soft_hyphens(&self) -> Cow<'_, IsoLatin1Str> {
+        if !self.as_bytes().contains(&SOFT_HYPHEN) {
+            return Cow::Borrowed(self);
+        }
+
+        let mut bytes = self.as_bytes().to_vec();
+        bytes.retain(|&byte| byte != SOFT_HYPHEN);
+
+        // SAFETY: the bytes come from a `IsoLatin1Str`.
+        Cow::Owned(unsafe { IsoLatin1String::from_iso8859_1_unchecked(bytes) })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn latin1(bytes: &[u8]) -> &IsoLatin1Str {
+        IsoLatin1Str::from_iso8859_1(bytes).unwrap()
+    }
+
+    #[test]
+    fn display_width() {
+        assert_eq!(latin1(b"").display_width(), 0);
+        assert_eq!(latin1(b"caf\xE9").display_width(), 4);
+        assert_eq!(latin1(b"\xAD\xAD").display_width(), 0);
+        assert_eq!(latin1(b"5\xA0kg").display_width(), 4);
+        assert_eq!(latin1(b"\x00\x07a\tb\x1B\x7F\n").display_width(), 2);
+        assert_eq!(latin1(b"\xA0\xAD\xFF").display_width(), 2);
+    }
+
+    #[test]
+    fn strip_soft_hyphens() {
+        let s = latin1(b"\xADin\xADter\xADna\xADtio\xADnal\xAD");
+        assert_eq!(&*s.strip_soft_hyphens(), "international");
+
+        let s = latin1(b"a\xA0b\t-c");
+        assert!(matches!(s.strip_soft_hyphens(), Cow::Borrowed(_)));
+
+        let s = IsoLatin1String::from_iso8859_1(b"\xE9\xADt\xE9".to_vec()).unwrap();
+        assert_eq!(s.strip_soft_hyphens().as_bytes(), b"\xE9t\xE9");
+        assert_eq!(s.display_width(), 3);
+    }
+}
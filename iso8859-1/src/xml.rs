@@ -0,0 +1,263 @@
+//! Integration with the [`quick_xml`] crate.
+//!
+//! `quick_xml` reads documents as bytes, so a document declared with `encoding="ISO-8859-1"` can
+//! be read as it is. The functions of this module then decode the text and attribute values of
+//! its events into [`IsoLatin1String`], resolving the predefined entities (`&amp;`, `&lt;`, ...)
+//! and the character references (`&#233;`, `&#xE9;`) on the way.
+//!
+//! This module is only available with the `quick-xml` feature.
+//!
+//! # Examples
+//!
+//! Basic usage:
+//!
+//! ```
+//! use iso8859_1::xml;
+//! use quick_xml::{events::Event, Reader};
+//!
+//! let doc = b"<?xml version=\"1.0\" encoding=\"ISO-8859-1\"?>\
+//!             <p lang=\"fr\">Caf\xE9 &amp; cr&#xE8;me</p>";
+//! let mut reader = Reader::from_reader(&doc[..]);
+//! let mut buf = Vec::new();
+//!
+//! let mut texts = Vec::new();
+//! loop {
+//!     match reader.read_event_into(&mut buf).unwrap() {
+//!         Event::Decl(decl) => assert!(xml::is_iso8859_1(&decl)),
+//!         Event::Text(text) => texts.push(xml::text(&text).unwrap()),
+//!         Event::Eof => break,
+//!         _ => {}
+//!     }
+//!     buf.clear();
+//! }
+//!
+//! assert_eq!(texts, ["Café & crème"]);
+//! ```
+
+use std::{error::Error, fmt};
+
+use quick_xml::events::{attributes::Attribute, BytesDecl, BytesText};
+
+use crate::{IsoLatin1Char, IsoLatin1Str, IsoLatin1String};
+
+/// The labels of ISO8859-1 registered by IANA, compared case-insensitively.
+const LABELS: [&[u8]; 8] = [
+    b"ISO-8859-1",
+    b"ISO_8859-1",
+    b"ISO_8859-1:1987",
+    b"latin1",
+    b"l1",
+    b"iso-ir-100",
+    b"IBM819",
+    b"CP819",
+];
+
+/// Returns `true` if the XML declaration declares the document as ISO8859-1.
+///
+/// Every label registered by IANA for ISO8859-1 is recognized, ignoring ASCII case.
+pub fn is_iso8859_1(decl: &BytesDecl<'_>) -> bool {
+    match decl.encoding() {
+        Some(Ok(encoding)) => LABELS
+            .iter()
+            .any(|label| label.eq_ignore_ascii_case(&encoding)),
+        _ => false,
+    }
+}
+
+/// Decodes the content of a text event, resolving its entity and character references.
+///
+/// # Errors
+///
+/// Returns [`Err`] if the text is not ISO8859-1, or has a reference that is malformed, to an
+/// unknown entity, or to a character that is not in ISO8859-1.
+pub fn text(text: &BytesText<'_>) -> Result<IsoLatin1String, XmlError> {
+    unescape(text)
+}
+
+/// Decodes the value of an attribute, resolving its entity and character references.
+///
+/// # Errors
+///
+/// Returns [`Err`] for the same reasons as [`text`].
+pub fn attribute_value(attribute: &Attribute<'_>) -> Result<IsoLatin1String, XmlError> {
+    unescape(&attribute.value)
+}
+
+/// Validates `raw` and resolves its references.
+fn unescape(raw: &[u8]) -> Result<IsoLatin1String, XmlError> {
+    let raw = IsoLatin1Str::from_iso8859_1(raw).map_err(|error| XmlError {
+        kind: XmlErrorKind::Invalid,
+        position: error.valid_up_to(),
+    })?;
+    let bytes = raw.as_bytes();
+
+    let mut unescaped = Vec::with_capacity(bytes.len());
+    let mut index = 0;
+    while let Some(offset) = bytes[index..].iter().position(|&byte| byte == b'&') {
+        let start = index + offset;
+        unescaped.extend_from_slice(&bytes[index..start]);
+
+        let end = bytes[start..]
+            .iter()
+            .position(|&byte| byte == b';')
+            .map(|offset| start + offset)
+            .ok_or(XmlError {
+                kind: XmlErrorKind::UnterminatedReference,
+                position: start,
+            })?;
+        unescaped.push(resolve(&bytes[start + 1..end], start)?);
+        index = end + 1;
+    }
+    unescaped.extend_from_slice(&bytes[index..]);
+
+    // SAFETY: the bytes come from a `IsoLatin1Str` or from `IsoLatin1Char`s.
+    Ok(unsafe { IsoLatin1String::from_iso8859_1_unchecked(unescaped) })
+}
+
+/// Resolves the reference `name`, found between a `&` at `position` and a `;`.
+fn resolve(name: &[u8], position: usize) -> Result<u8, XmlError> {
+    let error = |kind| XmlError { kind, position };
+
+    let code = match name {
+        b"lt" => return Ok(b'<'),
+        b"gt" => return Ok(b'>'),
+        b"amp" => return Ok(b'&'),
+        b"apos" => return Ok(b'\''),
+        b"quot" => return Ok(b'"'),
+        [b'#', b'x', hex @ ..] => parse_code(hex, 16),
+        [b'#', decimal @ ..] => parse_code(decimal, 10),
+        _ => return Err(error(XmlErrorKind::UnknownEntity)),
+    };
+
+    code.and_then(char::from_u32)
+        .and_then(|char| IsoLatin1Char::try_from(char).ok())
+        .map(u8::from)
+        .ok_or_else(|| error(XmlErrorKind::InvalidCharRef))
+}
+
+/// Parses the code point of a character reference.
+fn parse_code(digits: &[u8], radix: u32) -> Option<u32> {
+    if digits.is_empty() {
+        return None;
+    }
+    digits.iter().try_fold(0u32, |code, &digit| {
+        let digit = char::from(digit).to_digit(radix)?;
+        code.checked_mul(radix)?.checked_add(digit)
+    })
+}
+
+/// The kind of a [`XmlError`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum XmlErrorKind {
+    /// The raw text has a byte that is undefined in ISO8859-1.
+    Invalid,
+    /// A `&` is not closed by a `;`.
+    UnterminatedReference,
+    /// A reference to an entity other than the predefined ones.
+    UnknownEntity,
+    /// A malformed character reference, or one to a character that is not in ISO8859-1.
+    InvalidCharRef,
+}
+
+/// The error returned when the text of a XML event can not be decoded as ISO8859-1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct XmlError {
+    kind: XmlErrorKind,
+    position: usize,
+}
+
+impl XmlError {
+    /// Returns the kind of this error.
+    pub fn kind(&self) -> XmlErrorKind {
+        self.kind
+    }
+
+    /// Returns the index in the raw text of the invalid byte, or of the `&` starting the invalid
+    /// reference.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+}
+
+impl fmt::Display for XmlError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = match self.kind {
+            XmlErrorKind::Invalid => "byte undefined in ISO8859-1",
+            XmlErrorKind::UnterminatedReference => "unterminated reference",
+            XmlErrorKind::UnknownEntity => "unknown entity",
+            XmlErrorKind::InvalidCharRef => "character reference not in ISO8859-1",
+        };
+        write!(f, "{} at index {}", message, self.position)
+    }
+}
+
+impl Error for XmlError {}
+
+#[cfg(test)]
+mod tests {
+    use quick_xml::{events::Event, Reader};
+
+    use super::*;
+
+    #[test]
+    fn declaration() {
+        for (encoding, expected) in [
+            ("ISO-8859-1", true),
+            ("iso-8859-1", true),
+            ("Latin1", true),
+            ("UTF-8", false),
+            ("ISO-8859-10", false),
+        ] {
+            let decl = BytesDecl::new("1.0", Some(encoding), None);
+            assert_eq!(is_iso8859_1(&decl), expected, "{encoding}");
+        }
+        assert!(!is_iso8859_1(&BytesDecl::new("1.0", None, None)));
+    }
+
+    #[test]
+    fn unescape_references() {
+        let s = unescape(b"&lt;a&gt; &amp; &apos;&quot; &#233;&#xC9;&#xe9; \xFF").unwrap();
+        assert_eq!(s, "<a> & '\" éÉé ÿ");
+    }
+
+    #[test]
+    fn unescape_errors() {
+        let err = |raw: &[u8]| unescape(raw).unwrap_err();
+
+        assert_eq!(err(b"ab\x85").kind(), XmlErrorKind::Invalid);
+        assert_eq!(err(b"ab\x85").position(), 2);
+        assert_eq!(err(b"a &amp b").kind(), XmlErrorKind::UnterminatedReference);
+        assert_eq!(err(b"a &eacute;").kind(), XmlErrorKind::UnknownEntity);
+        assert_eq!(err(b"&#8364;").kind(), XmlErrorKind::InvalidCharRef);
+        assert_eq!(err(b"&#x85;").kind(), XmlErrorKind::InvalidCharRef);
+        assert_eq!(err(b"&#;").kind(), XmlErrorKind::InvalidCharRef);
+        assert_eq!(err(b"&#x;").kind(), XmlErrorKind::InvalidCharRef);
+        assert_eq!(err(b"&#99999999999;").kind(), XmlErrorKind::InvalidCharRef);
+        assert_eq!(err(b"ok &#xZZ;").position(), 3);
+    }
+
+    #[test]
+    fn attributes() {
+        let doc = b"<a title=\"Gr\xFC&#xDF;e &amp; mehr\" x=\"&#x20AC;\"/>";
+        let mut reader = Reader::from_reader(&doc[..]);
+        let mut buf = Vec::new();
+
+        match reader.read_event_into(&mut buf).unwrap() {
+            Event::Empty(start) => {
+                let mut attributes = start.attributes().map(Result::unwrap);
+                let title = attributes.next().unwrap();
+                assert_eq!(attribute_value(&title).unwrap(), "Grüße & mehr");
+
+                let x = attributes.next().unwrap();
+                let err = attribute_value(&x).unwrap_err();
+                assert_eq!(err.kind(), XmlErrorKind::InvalidCharRef);
+                assert_eq!(
+                    err.to_string(),
+                    "character reference not in ISO8859-1 at index 0"
+                );
+            }
+            event => panic!("unexpected event {event:?}"),
+        }
+    }
+}